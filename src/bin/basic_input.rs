@@ -74,6 +74,13 @@ use std::io::{self, BufRead};
 ///     Ok(())
 /// }
 /// ```
+///
+/// ### Missing `scanf`?
+///
+/// Once you are done, have a look at `scan!` in our library (`src/io.rs`).
+/// `scan!(scanner, "{} {}", i32, char)` reads a line like `42 x` into a tuple,
+/// much like `scanf("%d %c", &i, &c)`, and tells you which value was wrong
+/// instead of crashing. We'll learn how to write macros like this later.
 fn stdin_lock() -> io::Result<()> {
     todo!() // Paste code and complete it!
 }
@@ -0,0 +1,120 @@
+//! Bulls and Cows, the next step after `guess_number`.
+use rust_learning_group_public::{
+    guess::{
+        bulls::{self, Code, Solver, DIGITS},
+        Command,
+    },
+    i18n::tr_args,
+    io::{prompt_until_valid, InputError},
+    random::Rng,
+};
+use std::io::{self, BufRead, Write};
+
+/// How many guesses a player gets.
+const MAX_ATTEMPTS: u32 = 10;
+
+/// ### Bulls and Cows
+///
+/// I think of a code of 4 different digits, like `0482`, and you guess it.
+/// After every guess I answer `xA yB`: `x` digits are right and in the right
+/// place, `y` more digits are in my code but in another place.
+///
+/// The loop is the one from `guess_number`, but the answer is no longer an
+/// `Ordering`. Try writing `compare` yourself: the code is an array
+/// `[u8; 4]`, and one `for` loop with an `if`/`else if` is enough.
+///
+/// ```ignore
+/// fn compare(secret: [u8; 4], guess: [u8; 4]) -> (u32, u32) {
+///     let mut bulls = 0;
+///     let mut cows = 0;
+///     for i in 0..4 {
+///         /* FILL HERE! */
+///     }
+///     (bulls, cows)
+/// }
+/// ```
+///
+/// `cargo run --bin bulls_and_cows -- --solve` lets a bot play instead. It
+/// only guesses codes that agree with every answer so far. With
+/// `--benchmark` it plays all 5040 possible codes and shows how many
+/// guesses it needed on average.
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+    let mut rng = Rng::from_args_or_entropy();
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--benchmark") {
+        benchmark(&mut output)
+    } else if args.iter().any(|arg| arg == "--solve") {
+        solve(&mut output, Code::random(&mut rng))
+    } else {
+        play(&mut stdin.lock(), &mut output, Code::random(&mut rng))
+    }
+}
+
+fn play(input: &mut impl BufRead, output: &mut impl Write, secret: Code) -> io::Result<()> {
+    writeln!(
+        output,
+        "{}",
+        tr_args("bulls.start", &[&DIGITS, &MAX_ATTEMPTS])
+    )?;
+    for attempt in 1..=MAX_ATTEMPTS {
+        let prompt = format!("{} ", tr_args("bulls.attempt", &[&attempt]));
+        let guess = match prompt_until_valid(input, output, &prompt, None) {
+            Ok(Command::Value(guess)) => guess,
+            Ok(Command::Exit) | Err(InputError::Eof) | Err(InputError::Parse { .. }) => {
+                return writeln!(output, "{}", tr_args("bulls.secret", &[&secret]));
+            }
+            Err(InputError::Io(e)) => return Err(e),
+        };
+
+        let feedback = secret.compare(guess);
+        if feedback.is_win() {
+            return writeln!(output, "{}", tr_args("bulls.win", &[&feedback, &attempt]));
+        }
+        writeln!(output, "{}", feedback)?;
+    }
+    writeln!(output, "{}", tr_args("bulls.lost", &[&secret]))
+}
+
+/// Let the bot find `secret`, printing every guess and how many codes were
+/// still possible.
+fn solve(output: &mut impl Write, secret: Code) -> io::Result<()> {
+    writeln!(output, "{}", tr_args("bulls.solve", &[&secret]))?;
+    let mut solver = Solver::new();
+    let mut attempt = 0;
+    while let Some(guess) = solver.next_guess() {
+        attempt += 1;
+        let feedback = secret.compare(guess);
+        let left = solver.candidates().len();
+        writeln!(
+            output,
+            "{:>2}. {} {} ({} left)",
+            attempt, guess, feedback, left
+        )?;
+        if feedback.is_win() {
+            break;
+        }
+        solver.feedback(feedback);
+    }
+    Ok(())
+}
+
+fn benchmark(output: &mut impl Write) -> io::Result<()> {
+    let stats = bulls::benchmark();
+    writeln!(
+        output,
+        "{}",
+        tr_args(
+            "bulls.benchmark",
+            &[&stats.games, &format!("{:.3}", stats.mean()), &stats.worst]
+        )
+    )?;
+    let most = stats.distribution.values().copied().max().unwrap_or(1);
+    for (guesses, count) in &stats.distribution {
+        let bar = "#".repeat((count * 40).div_ceil(most));
+        writeln!(output, "{:>3} | {:<40} {}", guesses, bar, count)?;
+    }
+    Ok(())
+}
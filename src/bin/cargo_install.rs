@@ -1,11 +1,26 @@
 //! Install cargo toolchain.
+use rust_learning_group_public::i18n::tr;
 
 /// Try run this program on your computer and expect something fancy printed!
 ///
 /// 1. run `cargo build --bin cargo_install` to only build this binary.
 /// 2. run `cargo run --bin cargo_install` to only run this binary.
+/// 3. run `cargo run --bin cargo_install -- --lang zh` to read it in Chinese.
 fn main() {
     println!("{}", HELLO_MESSAGE);
+    println!("{}\n", indent(tr("cargo_install.welcome")));
+    println!("{}", indent(tr("cargo_install.contributors")));
+    println!("{}", CONTRIBUTORS);
+    println!("{}\n", indent(tr("cargo_install.more")));
+    println!("{}\n", indent(tr("cargo_install.contact")));
+    println!("{}", indent(tr("cargo_install.join")));
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 const HELLO_MESSAGE: &str = r#"
@@ -22,32 +37,13 @@ const HELLO_MESSAGE: &str = r#"
     |   ----------------+ |    | |    +--+    |
     |                   | |    | |            |
     +-------------------+ +----+ +------------+
-    ########### RUST LEARNING GROUP ###########
-    HELLO THERE!
-    Welcome to LCPU Rust Learning Group.
-    This learning group is created and managed
-    by LCPU (Linux Club of Peking University),
-    which is a non-official learning group for
-    all those who want to learn Rust programming
-    language. You will be learning mainly only
-    your own with "The book", attending group
-    talking weekly and sharing what you have dis-
-    covered.
+    ########### RUST LEARNING GROUP ###########"#;
 
-    Contributors:
-    Bohai Li      (李博海): AP of LCPU. Initializer
+const CONTRIBUTORS: &str = r#"    Bohai Li      (李博海): AP of LCPU. Initializer
     Yuanhang Sun  (孙远航): AP and previous leader of LCPU.
     Haonan Xue    (薛昊男): AP of LCPU.
     Zisu Zhang    (张子苏): AP of LCPU.
     QianKang Zhou (周乾康): AP of LCPU.
     Jingxiang Sha (沙靖翔): AP of LCPU.
     Yixuan Wang   (王奕轩): AP of LCPU.
-    Jiayi Zhuang  (庄嘉毅): AP of LCPU.
-    ...To be added.
-
-    Contact with us: lcpu@pku.edu.cn
-    
-    Looking forward if you want to join us and make
-    The learning group a better place for sharing
-    knowledge!
-"#;
+    Jiayi Zhuang  (庄嘉毅): AP of LCPU."#;
@@ -1,4 +1,6 @@
 //! Learning basic control flow: loops
+use rust_learning_group_public::{deck::MagicDeck, io::Scanner};
+use std::io::{self, BufRead, Write};
 
 /// ### Loops
 ///
@@ -104,6 +106,10 @@
 /// };
 /// ```
 ///
+/// You'll need this loop so often that our library has it ready as
+/// `rust_learning_group_public::io::prompt_until_valid`, which also lets you
+/// give up after a number of attempts.
+///
 /// ### Quiz: UNO!
 ///
 /// Let's play a game of UNO! You are given a deck of cards, each numbered from
@@ -128,14 +134,18 @@
 /// seed modulo 10. Thus, the first card you draw is 3, and the subsequent
 /// card's number is 6. If this calculation seems complex, fret not; we have
 /// implemented the magic deck for you - simply invoke the `draw_card()`
-/// function to acquire the next card.
+/// function to acquire the next card. (It is a `MagicDeck` from our library,
+/// which you can also create, clone and replay yourself.)
 ///
 /// The input for the game is provided in the following format: Each line
 /// contains a card presented by the judge, either a number from 0 to 12, "+2",
 /// or "+4". When the game ends, output "UNO!" and the total count of
 /// cards you have drawn on the next line.
 ///
-/// Complete the function `quiz` to solve the problem.
+/// Complete the function `quiz` to solve the problem. Read the judge's cards
+/// with `scanner.token::<String>()` and print to `output` with
+/// `writeln!(output, ...)?`, so the judge can play with your program without
+/// a keyboard.
 ///
 /// #### Example
 ///
@@ -154,35 +164,32 @@
 /// UNO!
 /// 4
 /// ```
-fn quiz() {
+///
+/// Once you have solved it, have a look at `rust_learning_group_public::uno`
+/// in our library. It plays the real game, with colors, Skip, Reverse, Draw
+/// Two, wild cards and up to 10 players, and it is mostly `enum`s and
+/// `match`, just like your quiz.
+fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    let mut scanner = Scanner::new(input);
     let mut total = 0;
     let mut cards = [0; 13];
-    
-    // Your code here
 
-    println!("{}", total);
-}
+    // Your code here
 
-fn read_line() -> String {
-    let mut buffer = String::new();
-    std::io::stdin().read_line(&mut buffer).unwrap();
-    buffer.trim().to_string()
+    writeln!(output, "{}", total)
 }
 
 /// A magic deck for you :D
 fn draw_card() -> usize {
-    use std::cell::Cell;
+    use std::cell::RefCell;
     thread_local! {
-        static SEED: Cell<i32> = const { Cell::new(0) };
+        static DECK: RefCell<MagicDeck> = RefCell::new(MagicDeck::new());
     }
-    SEED.with(|seed| {
-        let val = seed.get();
-        let next = (val * 71 + 3) % 100;
-        seed.set(next);
-        (next % 10) as usize
-    })
+    DECK.with(|deck| deck.borrow_mut().draw())
 }
 
 fn main() {
-    quiz()
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    quiz(&mut stdin.lock(), &mut stdout.lock()).unwrap()
 }
@@ -0,0 +1,705 @@
+//! Generate a static course website from the exercises' doc comments.
+//!
+//! Run `cargo run --bin course_site` from the repository root and open
+//! `site/index.html`. Use `--out <dir>` to write somewhere else.
+//!
+//! Weeks and their exercises are taken from `README.md`. Programs in
+//! `src/bin` that the README does not list yet end up under "More".
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Write as _},
+    path::{Path, PathBuf},
+};
+
+const README: &str = "README.md";
+const SOURCE_DIR: &str = "src/bin";
+const DEFAULT_OUT: &str = "site";
+/// Binaries in `src/bin` that are tools rather than lessons.
+const NOT_LESSONS: &[&str] = &["course_site", "guess_number_bots", "guess_number_net"];
+
+/// One `## Week...` section of the README.
+struct Week {
+    title: String,
+    exercises: Vec<String>,
+}
+
+/// A lesson extracted from `src/bin/<name>.rs`.
+struct Lesson {
+    name: String,
+    week: usize,
+    summary: String,
+    /// Markdown text, already stripped of `//!`, `///` and `/* */` markers.
+    body: String,
+}
+
+fn main() -> io::Result<()> {
+    let out = parse_out_dir();
+    let mut weeks = parse_weeks(&fs::read_to_string(README)?);
+    let more = unlisted_exercises(&weeks)?;
+    if !more.is_empty() {
+        weeks.push(Week {
+            title: "More".to_string(),
+            exercises: more,
+        });
+    }
+
+    let mut lessons = Vec::new();
+    for (week, w) in weeks.iter().enumerate() {
+        for name in &w.exercises {
+            let path = Path::new(SOURCE_DIR).join(format!("{}.rs", name));
+            let source = fs::read_to_string(&path)?;
+            lessons.push(extract_lesson(name, week, &source));
+        }
+    }
+
+    fs::create_dir_all(&out)?;
+    fs::write(out.join("style.css"), STYLE)?;
+    fs::write(out.join("index.html"), render_index(&weeks, &lessons))?;
+    for (i, lesson) in lessons.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| &lessons[i]);
+        let next = lessons.get(i + 1);
+        let page = render_lesson(&weeks, &lessons, lesson, prev, next);
+        fs::write(out.join(format!("{}.html", lesson.name)), page)?;
+    }
+
+    writeln!(
+        io::stdout().lock(),
+        "Wrote {} pages to {}",
+        lessons.len() + 1,
+        out.display()
+    )
+}
+
+fn parse_out_dir() -> PathBuf {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--out" {
+            if let Some(dir) = args.next() {
+                return PathBuf::from(dir);
+            }
+        } else if let Some(dir) = arg.strip_prefix("--out=") {
+            return PathBuf::from(dir);
+        }
+    }
+    PathBuf::from(DEFAULT_OUT)
+}
+
+/// Read `## Week...` headings and the `` - `exercise` `` items below them.
+fn parse_weeks(readme: &str) -> Vec<Week> {
+    let mut weeks: Vec<Week> = Vec::new();
+    let mut in_week = false;
+    for line in readme.lines() {
+        let line = line.trim();
+        if let Some(title) = line.strip_prefix("## ") {
+            in_week = title.starts_with("Week");
+            if in_week {
+                weeks.push(Week {
+                    title: title.to_string(),
+                    exercises: Vec::new(),
+                });
+            }
+        } else if in_week {
+            if let Some(item) = line.strip_prefix("- ") {
+                let name = item.trim_matches('`').to_string();
+                weeks.last_mut().unwrap().exercises.push(name);
+            }
+        }
+    }
+    weeks
+}
+
+fn unlisted_exercises(weeks: &[Week]) -> io::Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(SOURCE_DIR)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let listed = weeks.iter().any(|w| w.exercises.contains(&name));
+            if !listed && !NOT_LESSONS.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Collect the doc text of a source file in order.
+///
+/// `//!` and `///` lines are Markdown already. The `/* */` blocks used in
+/// `basics.rs` are free-form notes with diagrams, so they become code
+/// blocks to keep their layout.
+fn extract_lesson(name: &str, week: usize, source: &str) -> Lesson {
+    let mut body = String::new();
+    let mut summary = String::new();
+    let mut in_block = false;
+    let mut previous_was_doc = false;
+
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        if in_block {
+            if let Some(end) = trimmed.find("*/") {
+                push_line(&mut body, trimmed[..end].trim_end());
+                body.push_str("```\n\n");
+                in_block = false;
+            } else {
+                push_line(&mut body, line.trim_end());
+            }
+            continue;
+        }
+
+        let doc = trimmed
+            .strip_prefix("//!")
+            .or_else(|| trimmed.strip_prefix("///"));
+        if let Some(text) = doc {
+            let text = text.strip_prefix(' ').unwrap_or(text);
+            if summary.is_empty() && trimmed.starts_with("//!") {
+                summary = text.trim().to_string();
+            } else {
+                push_line(&mut body, text.trim_end());
+            }
+            previous_was_doc = true;
+        } else if let Some(rest) = trimmed.strip_prefix("/*") {
+            if previous_was_doc {
+                body.push('\n');
+            }
+            body.push_str("```text\n");
+            match rest.find("*/") {
+                Some(end) => {
+                    push_line(&mut body, rest[..end].trim());
+                    body.push_str("```\n\n");
+                }
+                None => {
+                    push_line(&mut body, rest.trim());
+                    in_block = true;
+                }
+            }
+            previous_was_doc = false;
+        } else if previous_was_doc {
+            // A doc block ended; keep separate blocks apart.
+            body.push('\n');
+            previous_was_doc = false;
+        }
+    }
+
+    Lesson {
+        name: name.to_string(),
+        week,
+        summary,
+        body,
+    }
+}
+
+fn push_line(body: &mut String, line: &str) {
+    body.push_str(line);
+    body.push('\n');
+}
+
+fn render_index(weeks: &[Week], lessons: &[Lesson]) -> String {
+    let mut content = String::from("<h1>Rust learning group</h1>\n");
+    for (i, week) in weeks.iter().enumerate() {
+        let _ = writeln!(content, "<h2>{}</h2>\n<ul>", escape(&week.title));
+        for lesson in lessons.iter().filter(|l| l.week == i) {
+            let _ = writeln!(
+                content,
+                "<li><a href=\"{0}.html\"><code>{0}</code></a> &mdash; {1}</li>",
+                lesson.name,
+                render_inline(&lesson.summary)
+            );
+        }
+        content.push_str("</ul>\n");
+    }
+    page("Rust learning group", weeks, lessons, None, &content)
+}
+
+fn render_lesson(
+    weeks: &[Week],
+    lessons: &[Lesson],
+    lesson: &Lesson,
+    prev: Option<&Lesson>,
+    next: Option<&Lesson>,
+) -> String {
+    let mut content = String::new();
+    let _ = writeln!(
+        content,
+        "<p class=\"week\">{}</p>\n<h1><code>{}</code></h1>\n<p class=\"summary\">{}</p>",
+        escape(&weeks[lesson.week].title),
+        lesson.name,
+        render_inline(&lesson.summary)
+    );
+    content.push_str(&render_markdown(&lesson.body));
+    let _ = writeln!(
+        content,
+        "<p class=\"run\">Run it with <code>cargo run --bin {}</code>.</p>",
+        lesson.name
+    );
+
+    content.push_str("<nav class=\"pager\">");
+    if let Some(prev) = prev {
+        let _ = write!(
+            content,
+            "<a class=\"prev\" href=\"{0}.html\">&larr; {0}</a>",
+            prev.name
+        );
+    }
+    match next {
+        Some(next) => {
+            let _ = write!(
+                content,
+                "<a class=\"next\" href=\"{0}.html\">Next: {0} &rarr;</a>",
+                next.name
+            );
+        }
+        None => content.push_str("<a class=\"next\" href=\"index.html\">Back to index</a>"),
+    }
+    content.push_str("</nav>\n");
+
+    page(&lesson.name, weeks, lessons, Some(&lesson.name), &content)
+}
+
+fn page(
+    title: &str,
+    weeks: &[Week],
+    lessons: &[Lesson],
+    current: Option<&str>,
+    content: &str,
+) -> String {
+    let mut nav = String::from("<nav class=\"weeks\">\n<a href=\"index.html\">Index</a>\n");
+    for (i, week) in weeks.iter().enumerate() {
+        let _ = writeln!(nav, "<h3>{}</h3>\n<ul>", escape(&week.title));
+        for lesson in lessons.iter().filter(|l| l.week == i) {
+            let class = if current == Some(lesson.name.as_str()) {
+                " class=\"current\""
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                nav,
+                "<li><a{1} href=\"{0}.html\">{0}</a></li>",
+                lesson.name, class
+            );
+        }
+        nav.push_str("</ul>\n");
+    }
+    nav.push_str("</nav>\n");
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n\
+         </head>\n<body>\n{}<main>\n{}</main>\n</body>\n</html>\n",
+        escape(title),
+        nav,
+        content
+    )
+}
+
+/// Render the Markdown subset used in the lessons: headings, paragraphs,
+/// lists, fenced code and inline code, emphasis and links.
+///
+/// Everything under a heading containing "Quiz" is wrapped in a
+/// `<section class="quiz">` until the next heading of the same level.
+fn render_markdown(text: &str) -> String {
+    let (lines, links) = collect_link_definitions(text);
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<&str> = None;
+    let mut quiz_level: Option<usize> = None;
+    let mut code: Option<(String, String)> = None;
+
+    for line in lines {
+        if let Some((lang, buffer)) = code.as_mut() {
+            if line.trim_start().starts_with("```") {
+                let _ = writeln!(
+                    html,
+                    "<pre><code class=\"language-{}\">{}</code></pre>",
+                    lang,
+                    highlight(lang, buffer)
+                );
+                code = None;
+            } else {
+                buffer.push_str(line);
+                buffer.push('\n');
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            flush_paragraph(&mut html, &mut paragraph, &links);
+            close_list(&mut html, &mut list);
+            code = Some((code_language(info).to_string(), String::new()));
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut html, &mut paragraph, &links);
+            close_list(&mut html, &mut list);
+        } else if let Some((level, title)) = heading(trimmed) {
+            flush_paragraph(&mut html, &mut paragraph, &links);
+            close_list(&mut html, &mut list);
+            if quiz_level.is_some_and(|quiz| level <= quiz) {
+                html.push_str("</section>\n");
+                quiz_level = None;
+            }
+            if quiz_level.is_none() && title.contains("Quiz") {
+                html.push_str("<section class=\"quiz\">\n");
+                quiz_level = Some(level);
+            }
+            let _ = writeln!(
+                html,
+                "<h{0}>{1}</h{0}>",
+                level + 1,
+                render_links(title, &links)
+            );
+        } else if let Some((tag, item)) = list_item(trimmed) {
+            flush_paragraph(&mut html, &mut paragraph, &links);
+            if list != Some(tag) {
+                close_list(&mut html, &mut list);
+                let _ = writeln!(html, "<{}>", tag);
+                list = Some(tag);
+            }
+            let _ = writeln!(html, "<li>{}</li>", render_links(item, &links));
+        } else if list.is_some() && line.starts_with(' ') {
+            // Continuation of the previous list item.
+            html.truncate(html.len() - "</li>\n".len());
+            let _ = writeln!(html, " {}</li>", render_links(trimmed, &links));
+        } else {
+            close_list(&mut html, &mut list);
+            paragraph.push(trimmed);
+        }
+    }
+
+    flush_paragraph(&mut html, &mut paragraph, &links);
+    close_list(&mut html, &mut list);
+    if quiz_level.is_some() {
+        html.push_str("</section>\n");
+    }
+    html
+}
+
+/// Split out `[name]: url` lines so reference-style links can be resolved.
+fn collect_link_definitions(text: &str) -> (Vec<&str>, Vec<(String, String)>) {
+    let mut lines = Vec::new();
+    let mut links = Vec::new();
+    for line in text.lines() {
+        let definition = line
+            .trim()
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("]: "));
+        match definition {
+            Some((name, url)) => links.push((name.to_string(), url.trim().to_string())),
+            None => lines.push(line),
+        }
+    }
+    (lines, links)
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        Some((level, line[level..].trim()))
+    } else {
+        None
+    }
+}
+
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some(("ul", item));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        if let Some(item) = line[digits..].strip_prefix(". ") {
+            return Some(("ol", item));
+        }
+    }
+    None
+}
+
+fn code_language(info: &str) -> &str {
+    match info.trim() {
+        "" | "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail" => "rust",
+        "c" => "c",
+        "cpp" | "c++" => "cpp",
+        "py" | "python" => "python",
+        _ => "text",
+    }
+}
+
+fn flush_paragraph(html: &mut String, paragraph: &mut Vec<&str>, links: &[(String, String)]) {
+    if !paragraph.is_empty() {
+        let _ = writeln!(html, "<p>{}</p>", render_links(&paragraph.join(" "), links));
+        paragraph.clear();
+    }
+}
+
+fn close_list(html: &mut String, list: &mut Option<&str>) {
+    if let Some(tag) = list.take() {
+        let _ = writeln!(html, "</{}>", tag);
+    }
+}
+
+/// Turn `[text](url)` and `[text]` (with a matching definition) into
+/// anchors, rendering the rest as inline Markdown.
+fn render_links(text: &str, links: &[(String, String)]) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let Some(len) = rest[start..].find(']') else {
+            break;
+        };
+        let label = &rest[start + 1..start + len];
+        let after = &rest[start + len + 1..];
+
+        let inline = after
+            .strip_prefix('(')
+            .and_then(|url| url.find(')').map(|end| (&url[..end], &url[end + 1..])));
+        let target = match inline {
+            Some((url, remaining)) => Some((url.to_string(), remaining)),
+            None => links
+                .iter()
+                .find(|(name, _)| name == label)
+                .map(|(_, url)| (url.clone(), after)),
+        };
+
+        match target {
+            Some((url, remaining)) => {
+                html.push_str(&render_inline(&rest[..start]));
+                let _ = write!(
+                    html,
+                    "<a href=\"{}\">{}</a>",
+                    escape(&url),
+                    render_inline(label)
+                );
+                rest = remaining;
+            }
+            None => {
+                html.push_str(&render_inline(&rest[..start + len + 1]));
+                rest = after;
+            }
+        }
+    }
+    html.push_str(&render_inline(rest));
+    html
+}
+
+/// Inline code and `**bold**`.
+fn render_inline(text: &str) -> String {
+    let mut html = String::new();
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            let _ = write!(html, "<code>{}</code>", escape(part));
+        } else {
+            for (j, part) in escape(part).split("**").enumerate() {
+                if j % 2 == 1 {
+                    let _ = write!(html, "<strong>{}</strong>", part);
+                } else {
+                    html.push_str(part);
+                }
+            }
+        }
+    }
+    html
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A small tokenizer good enough for the snippets in the lessons:
+/// keywords, strings, chars, numbers and comments.
+fn highlight(lang: &str, code: &str) -> String {
+    let keywords: &[&str] = match lang {
+        "rust" => &[
+            "as", "break", "const", "continue", "else", "enum", "false", "fn", "for", "if", "impl",
+            "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+            "Self", "static", "struct", "trait", "true", "type", "use", "where", "while",
+        ],
+        "c" | "cpp" => &[
+            "bool", "break", "char", "const", "continue", "double", "else", "float", "for", "if",
+            "int", "long", "return", "struct", "unsigned", "void", "while", "#include",
+        ],
+        "python" => &[
+            "def", "elif", "else", "False", "for", "if", "import", "in", "None", "print", "return",
+            "True", "while",
+        ],
+        _ => return escape(code),
+    };
+    let line_comment = if lang == "python" { "#" } else { "//" };
+
+    let mut html = String::new();
+    let mut rest = code;
+    while let Some(c) = rest.chars().next() {
+        let token_len = if rest.starts_with(line_comment) {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            span(&mut html, "comment", &rest[..len]);
+            len
+        } else if c == '"' || (c == '\'' && lang != "rust") || is_char_literal(rest) {
+            let len = quoted_len(rest, c);
+            span(&mut html, "string", &rest[..len]);
+            len
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .unwrap_or(rest.len());
+            span(&mut html, "number", &rest[..len]);
+            len
+        } else if c.is_alphabetic() || c == '_' || c == '#' {
+            let len = rest[c.len_utf8()..]
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map_or(rest.len(), |len| len + c.len_utf8());
+            let word = &rest[..len];
+            if keywords.contains(&word) {
+                span(&mut html, "keyword", word);
+            } else if rest[len..].starts_with('!') && lang == "rust" {
+                span(&mut html, "macro", &rest[..len + 1]);
+                rest = &rest[len + 1..];
+                continue;
+            } else {
+                html.push_str(&escape(word));
+            }
+            len
+        } else {
+            html.push_str(&escape(&rest[..c.len_utf8()]));
+            c.len_utf8()
+        };
+        rest = &rest[token_len..];
+    }
+    html
+}
+
+/// Tell `'a'` apart from a lifetime such as `'outer`.
+fn is_char_literal(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next() == Some('\'')
+        && match chars.next() {
+            Some('\\') => true,
+            Some(_) => chars.next() == Some('\''),
+            None => false,
+        }
+}
+
+fn quoted_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '\n' => return i,
+            c if c == quote && !escaped => return i + 1,
+            _ => escaped = false,
+        }
+    }
+    text.len()
+}
+
+fn span(html: &mut String, class: &str, text: &str) {
+    let _ = write!(html, "<span class=\"{}\">{}</span>", class, escape(text));
+}
+
+const STYLE: &str = r#"body {
+    display: flex;
+    margin: 0;
+    font-family: sans-serif;
+    line-height: 1.6;
+    color: #222;
+}
+nav.weeks {
+    flex: 0 0 14em;
+    padding: 1em;
+    background: #f4f1ee;
+    min-height: 100vh;
+}
+nav.weeks ul { list-style: none; padding-left: 0.5em; }
+nav.weeks a.current { font-weight: bold; }
+main { max-width: 48em; padding: 1em 2em; }
+p.week { color: #888; margin-bottom: 0; }
+pre { background: #f6f6f6; padding: 0.8em; overflow-x: auto; }
+code { font-family: monospace; }
+section.quiz {
+    border-left: 4px solid #dea584;
+    background: #fdf7f3;
+    padding: 0 1em;
+}
+nav.pager { display: flex; justify-content: space-between; margin: 2em 0; }
+nav.pager a.next { margin-left: auto; }
+span.keyword { color: #8959a8; font-weight: bold; }
+span.string { color: #718c00; }
+span.number { color: #f5871f; }
+span.comment { color: #8e908c; font-style: italic; }
+span.macro { color: #3e999f; }
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::{highlight, is_char_literal, quoted_len, render_markdown};
+
+    #[test]
+    fn quiz_section_ends_at_the_next_heading_of_its_level() {
+        let html = render_markdown("### Quiz: UNO!\n\nPlay.\n\n#### Input\n\n### Loops\n\nMore.");
+        assert_eq!(
+            html,
+            "<section class=\"quiz\">\n<h4>Quiz: UNO!</h4>\n<p>Play.</p>\n<h5>Input</h5>\n\
+             </section>\n<h4>Loops</h4>\n<p>More.</p>\n"
+        );
+    }
+
+    #[test]
+    fn reference_links_are_resolved() {
+        let html = render_markdown(
+            "See the [Reference] and [docs](https://docs.rs).\n\n\
+             [Reference]: https://doc.rust-lang.org/reference/",
+        );
+        assert_eq!(
+            html,
+            "<p>See the <a href=\"https://doc.rust-lang.org/reference/\">Reference</a> \
+             and <a href=\"https://docs.rs\">docs</a>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn list_items_continue_on_indented_lines() {
+        let html = render_markdown("1. `trim`: removes\n    whitespace.\n2. `retain`");
+        assert_eq!(
+            html,
+            "<ol>\n<li><code>trim</code>: removes whitespace.</li>\n<li><code>retain</code></li>\n</ol>\n"
+        );
+    }
+
+    #[test]
+    fn lifetimes_are_not_char_literals() {
+        assert!(is_char_literal("'a'"));
+        assert!(is_char_literal("'\\n'"));
+        assert!(!is_char_literal("'outer: for"));
+        assert_eq!(
+            highlight("rust", "'outer: loop { 'x' }"),
+            "'outer: <span class=\"keyword\">loop</span> { <span class=\"string\">'x'</span> }"
+        );
+    }
+
+    #[test]
+    fn quoted_len_skips_escaped_quotes() {
+        assert_eq!(quoted_len(r#""say \"hi\"" rest"#, '"'), 12);
+        assert_eq!(quoted_len("\"open\nnext", '"'), 5);
+    }
+
+    #[test]
+    fn code_is_html_escaped() {
+        assert_eq!(
+            render_markdown("Use `Vec<&str>` here."),
+            "<p>Use <code>Vec&lt;&amp;str&gt;</code> here.</p>\n"
+        );
+        assert_eq!(
+            render_markdown("```\nif a < b && c > \"d\" {}\n```"),
+            "<pre><code class=\"language-rust\"><span class=\"keyword\">if</span> a &lt; b \
+             &amp;&amp; c &gt; <span class=\"string\">&quot;d&quot;</span> {}\n</code></pre>\n"
+        );
+    }
+}
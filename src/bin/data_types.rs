@@ -1,4 +1,6 @@
 //! Basic data types in Rust: numbers and booleans
+use rust_learning_group_public::io::Scanner;
+use std::io::{self, BufRead, Write};
 
 /// ### Numbers and Booleans in Rust
 ///
@@ -127,41 +129,37 @@
 ///
 /// Complete the function `quiz` to solve the problem.
 ///
+/// The quiz reads from `input` and writes to `output` instead of using
+/// stdin and stdout directly, so it can be fed any text, not only what you
+/// type in. Print your answers with `writeln!(output, ...)?` the same way
+/// you would use `println!`. A `Scanner` reads the numbers for you, just
+/// like `std::cin >> x` does in C++.
+///
 /// ```
-/// fn quiz() {
-///     let x = read_i32();
-///     let y = read_f64();
-///     
+/// fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+///     let mut scanner = Scanner::new(input);
+///     let x: i32 = scanner.token().unwrap();
+///     let y: f64 = scanner.token().unwrap();
+///
 ///     // Your code here
+///
+///     Ok(())
 /// }
 /// ```
 ///
 /// [the Rust Reference]: https://doc.rust-lang.org/reference/expressions/operator-expr.html#numeric-cast
-fn quiz() {
-    let x = read_i32();
-    let y = read_f64();
+fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    let mut scanner = Scanner::new(input);
+    let x: i32 = scanner.token().unwrap();
+    let y: f64 = scanner.token().unwrap();
 
     // Your code here
-}
-
-fn read_i32() -> i32 {
-    read()
-}
-
-fn read_f64() -> f64 {
-    read()
-}
 
-fn read<T>() -> T
-where
-    T: std::str::FromStr,
-    T::Err: std::fmt::Debug,
-{
-    let mut buffer = String::new();
-    std::io::stdin().read_line(&mut buffer).unwrap();
-    buffer.trim().parse::<T>().unwrap()
+    Ok(())
 }
 
 fn main() {
-    quiz()
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    quiz(&mut stdin.lock(), &mut stdout.lock()).unwrap()
 }
@@ -1,4 +1,6 @@
 //! Basic data types in Rust: characters and strings
+use rust_learning_group_public::io::Scanner;
+use std::io::{self, BufRead, Write};
 
 /// ### Characters and Strings in Rust
 ///
@@ -142,42 +144,43 @@
 /// 3. `push_str`: `s.push_str(t)` appends the string `t` to the string `s`.
 ///     Note that `s` must be a mutable `String`, while `t` must be a `&str`.
 ///     This is also an in-place operation.
-/// 4. `read_line` and `parse_i32`: These functions are provided for you to
-///     read a line from standard input and parse a string into an integer,
-///     respectively.
+/// 4. `scanner.raw_line()` and `scanner.token::<i32>()`: These are provided
+///     for you to read a line from `input` and read an integer,
+///     respectively. `raw_line` gives you the line exactly as it was typed,
+///     spaces included, so getting rid of them is up to you. Both return an
+///     `Option`, which is `None` when there is no input left.
 ///
 /// Complete the `quiz` function to solve this problem.
 ///
+/// The quiz reads from `input` and writes to `output` instead of using
+/// stdin and stdout directly, so it can be fed any text, not only what you
+/// type in. Print your answer with `writeln!(output, ...)?` the same way
+/// you would use `println!`.
+///
 /// ```
-/// fn quiz() {
+/// fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+///     let mut scanner = Scanner::new(input);
 ///     let mut result = String::new();
 ///
 ///     // Your code here
 ///
-///     println!("{}", result);
+///     writeln!(output, "{}", result)
 /// }
 /// ```
 /// 
 /// [Raw Literals in Rust Reference]: https://doc.rust-lang.org/reference/tokens.html#raw-string-literals
 ///
-fn quiz() {
+fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    let mut scanner = Scanner::new(input);
     let mut result = String::new();
 
     // Your code here
-    
-    println!("{}", result);
-}
-
-fn read_line() -> String {
-    let mut buffer = String::new();
-    std::io::stdin().read_line(&mut buffer).unwrap();
-    buffer
-}
 
-fn parse_i32(s: &str) -> i32 {
-    s.trim().parse::<i32>().unwrap()
+    writeln!(output, "{}", result)
 }
 
 fn main() {
-    quiz()
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    quiz(&mut stdin.lock(), &mut stdout.lock()).unwrap()
 }
@@ -1,20 +1,47 @@
 //! Implement a guessing number program.
+use rust_learning_group_public::{
+    guess::{
+        reverse::{Answer, Contradiction, Guesser},
+        scores::{self, Score},
+        Command, Difficulty, Game, Hint, Outcome,
+    },
+    i18n::{tr, tr_args},
+    io::{prompt_until_valid, InputError},
+    random::Rng,
+};
 use std::{
     cmp::Ordering,
-    io::{self, BufRead},
+    io::{self, BufRead, Write},
+    ops::RangeInclusive,
+    path::Path,
+    str::FromStr,
+    time::Instant,
 };
 
+/// Where won games are kept, see `guess::scores` for the format.
+const SCORES_FILE: &str = "guess_number_scores.txt";
+
+/// How many scores `--scores` shows for each difficulty.
+const TOP_SCORES: usize = 5;
+
 /// ### Program
 ///
+/// Rust's standard library has no random numbers, so we use the small
+/// generator in our library: `rng.range(1..=100)` picks a number from 1 to
+/// 100.
+///
 /// ```ignore
 /// use std::{cmp::Ordering, io::{self, BufRead}};
+/// use rust_learning_group_public::{i18n::tr, random::Rng};
 ///
 /// fn main() {
+///     // Pass `--seed 42` to get the same number every time.
+///     let mut rng = Rng::from_args_or_entropy();
 ///     let secret_number: i32 = /* FILL HERE! */; // Don't let others know this!
 ///
-///     println!("Guess the number!");
+///     println!("{}", tr("guess_number.title")); // "Guess the number!"
 ///
-///     println!("Please input your guess. Type `exit` to exit.");
+///     println!("{}", tr("guess_number.prompt"));
 ///     
 ///     let mut buffer = String::new();
 ///     // TODO: initialize stdin here!
@@ -42,6 +69,383 @@ use std::{
 ///     }
 /// }
 /// ```
-fn main() {
-    todo!() // Paste code and complete it!
+///
+/// ### The full game
+///
+/// Write the program above yourself first, then compare it with the full
+/// game below. It is the same loop with a few extras: a difficulty to pick,
+/// a limited number of attempts, "warmer"/"colder" hints and another round
+/// if you like.
+///
+/// Run `cargo run --bin guess_number -- --seed 42` to get the same secret
+/// numbers every time.
+///
+/// With `--reverse` the roles are swapped: you think of a number and the
+/// computer guesses, and it will notice if your answers contradict each
+/// other. Add `--lies 2` to allow up to two lies (this is called Ulam's
+/// game) and see how many more guesses that costs.
+///
+/// Every won game goes into `guess_number_scores.txt` under the name given
+/// with `--name` (or your user name). `--scores` shows the best games of
+/// each difficulty, and `--verify` replays every game in the file from its
+/// seed, so a score edited by hand is caught.
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--scores") {
+        return show_scores(&mut stdout.lock(), Path::new(SCORES_FILE));
+    }
+    if args.iter().any(|arg| arg == "--verify") {
+        return verify_scores(&mut stdout.lock(), Path::new(SCORES_FILE));
+    }
+
+    let mut rng = Rng::from_args_or_entropy();
+    let mode = Mode::from_args();
+    let name = player_name();
+    play(&mut stdin.lock(), &mut stdout.lock(), &mut rng, mode, &name)
+}
+
+/// The name from `--name`, or else the user name. Spaces would break the
+/// scores file and a leading `#` would make the line a comment, so they
+/// become `_`.
+fn player_name() -> String {
+    let mut args = std::env::args().skip_while(|arg| arg != "--name").skip(1);
+    let name = args
+        .next()
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_default();
+    let mut name = name.split_whitespace().collect::<Vec<_>>().join("_");
+    if name.starts_with('#') {
+        name.replace_range(..1, "_");
+    }
+    if name.is_empty() {
+        "anonymous".to_string()
+    } else {
+        name
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    /// The player guesses the computer's number.
+    Guess,
+    /// The computer guesses the player's number, who may lie `max_lies`
+    /// times.
+    Reverse { max_lies: u32 },
+}
+
+impl Mode {
+    fn from_args() -> Mode {
+        let mut mode = Mode::Guess;
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--reverse" => mode = Mode::Reverse { max_lies: 0 },
+                "--lies" => {
+                    let value = args.next().unwrap_or_default();
+                    let Ok(max_lies) = value.parse() else {
+                        eprintln!("error: --lies needs a number, not `{}`", value);
+                        eprintln!("usage: guess_number [--reverse] [--lies N] [--name NAME] [--seed SEED] [--scores] [--verify]");
+                        std::process::exit(2);
+                    };
+                    mode = Mode::Reverse { max_lies };
+                }
+                _ => {}
+            }
+        }
+        mode
+    }
+}
+
+fn play(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    rng: &mut Rng,
+    mode: Mode,
+    name: &str,
+) -> io::Result<()> {
+    let title = match mode {
+        Mode::Guess => tr("guess_number.title"),
+        Mode::Reverse { .. } => tr("guess_number.reverse.title"),
+    };
+    writeln!(output, "{}", title)?;
+    loop {
+        for difficulty in Difficulty::ALL {
+            let range = difficulty.range();
+            let line = match mode {
+                Mode::Guess => tr_args(
+                    "guess_number.difficulty",
+                    &[
+                        &difficulty,
+                        range.start(),
+                        range.end(),
+                        &difficulty.max_attempts(),
+                    ],
+                ),
+                Mode::Reverse { .. } => tr_args(
+                    "guess_number.reverse.difficulty",
+                    &[&difficulty, range.start(), range.end()],
+                ),
+            };
+            writeln!(output, "{}", line)?;
+        }
+        let Some(difficulty) = read(input, output, tr("guess_number.choose"))? else {
+            break;
+        };
+
+        let finished = match mode {
+            Mode::Guess => {
+                // The game gets its own seed so the score can be replayed.
+                let seed = rng.next_u64();
+                let mut game = Game::new(difficulty, &mut Rng::new(seed));
+                let started = Instant::now();
+                let finished = play_round(input, output, &mut game)?;
+                if game.is_won() {
+                    let seconds = started.elapsed().as_secs();
+                    let score = Score::new(name, difficulty, seed, seconds, &game);
+                    save_score(output, &score)?;
+                }
+                finished
+            }
+            Mode::Reverse { max_lies } => {
+                play_reverse_round(input, output, difficulty.range(), max_lies)?
+            }
+        };
+        if !finished {
+            break;
+        }
+
+        match read(input, output, tr("guess_number.again"))? {
+            Some(YesNo(true)) => continue,
+            _ => break,
+        }
+    }
+    writeln!(output, "{}", tr("guess_number.bye"))
+}
+
+/// Play until the game is won or lost. Returns `false` if the player typed
+/// `exit` instead.
+fn play_round(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    game: &mut Game,
+) -> io::Result<bool> {
+    let range = game.range();
+    writeln!(
+        output,
+        "{}",
+        tr_args(
+            "guess_number.start",
+            &[range.start(), range.end(), &game.attempts_left()]
+        )
+    )?;
+    writeln!(output, "{}", tr("guess_number.prompt"))?;
+
+    loop {
+        let prompt = tr_args("guess_number.attempt", &[&(game.attempts() + 1)]);
+        let Some(guess) = read(input, output, &prompt)? else {
+            return Ok(false);
+        };
+
+        match game.guess(guess) {
+            Outcome::Win => {
+                writeln!(
+                    output,
+                    "{}",
+                    tr_args("guess_number.win", &[&game.attempts()])
+                )?;
+                return Ok(true);
+            }
+            Outcome::Lost => {
+                writeln!(
+                    output,
+                    "{}",
+                    tr_args("guess_number.lost", &[&game.secret()])
+                )?;
+                return Ok(true);
+            }
+            Outcome::OutOfRange => {
+                let message = tr_args("guess_number.out_of_range", &[range.start(), range.end()]);
+                writeln!(output, "{}", message)?;
+            }
+            Outcome::Wrong { ordering, hint } => {
+                let message = match ordering {
+                    Ordering::Less => tr("guess_number.less"),
+                    Ordering::Greater => tr("guess_number.greater"),
+                    Ordering::Equal => unreachable!("an equal guess wins"),
+                };
+                match hint {
+                    Some(Hint::Warmer) => {
+                        writeln!(output, "{} {}", message, tr("guess_number.warmer"))?
+                    }
+                    Some(Hint::Colder) => {
+                        writeln!(output, "{} {}", message, tr("guess_number.colder"))?
+                    }
+                    Some(Hint::Same) => {
+                        writeln!(output, "{} {}", message, tr("guess_number.same"))?
+                    }
+                    None => writeln!(output, "{}", message)?,
+                }
+            }
+        }
+    }
+}
+
+/// Add a won game to the scores file. Failing to save is not worth ending
+/// the game for, so it is only reported.
+fn save_score(output: &mut impl Write, score: &Score) -> io::Result<()> {
+    match scores::append(Path::new(SCORES_FILE), score) {
+        Ok(()) => writeln!(
+            output,
+            "{}",
+            tr_args("guess_number.scores.saved", &[&SCORES_FILE])
+        ),
+        Err(e) => writeln!(
+            output,
+            "{}",
+            tr_args("guess_number.scores.not_saved", &[&SCORES_FILE, &e])
+        ),
+    }
+}
+
+/// Print the best games of each difficulty, leaving out any that fail to
+/// replay.
+fn show_scores(output: &mut impl Write, path: &Path) -> io::Result<()> {
+    let all: Vec<Score> = scores::load(path)?
+        .into_iter()
+        .filter_map(|entry| entry.score.ok())
+        .filter(|score| score.verify().is_ok())
+        .collect();
+    for difficulty in Difficulty::ALL {
+        writeln!(
+            output,
+            "{}",
+            tr_args("guess_number.scores.title", &[&difficulty])
+        )?;
+        let best = scores::top(&all, difficulty, TOP_SCORES);
+        if best.is_empty() {
+            writeln!(output, "  {}", tr("guess_number.scores.none"))?;
+        }
+        for (rank, score) in best.iter().enumerate() {
+            let line = tr_args(
+                "guess_number.scores.entry",
+                &[&(rank + 1), &score.name, &score.attempts, &score.seconds],
+            );
+            writeln!(output, "  {}", line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Replay every game in the scores file and report the ones that don't
+/// hold up.
+fn verify_scores(output: &mut impl Write, path: &Path) -> io::Result<()> {
+    let all = scores::load(path)?;
+    let mut failed = 0;
+    for entry in &all {
+        match entry.score.clone().and_then(|score| score.verify()) {
+            Ok(()) => writeln!(output, "OK      {}", entry.text)?,
+            Err(reason) => {
+                failed += 1;
+                writeln!(
+                    output,
+                    "FAILED  {} (line {}: {})",
+                    entry.text, entry.line, reason
+                )?;
+            }
+        }
+    }
+    writeln!(
+        output,
+        "{}",
+        tr_args("guess_number.scores.verified", &[&all.len(), &failed])
+    )
+}
+
+/// Let the computer guess the player's number. Returns `false` if the
+/// player typed `exit`.
+fn play_reverse_round(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    range: RangeInclusive<i32>,
+    max_lies: u32,
+) -> io::Result<bool> {
+    let start = tr_args("guess_number.reverse.start", &[range.start(), range.end()]);
+    writeln!(output, "{}", start)?;
+    if max_lies > 0 {
+        writeln!(
+            output,
+            "{}",
+            tr_args("guess_number.reverse.lies", &[&max_lies])
+        )?;
+    }
+
+    let mut guesser = Guesser::new(range, max_lies);
+    loop {
+        let guess = guesser.next_guess();
+        let prompt = tr_args("guess_number.reverse.question", &[&guess]);
+        let Some(answer) = read::<Answer>(input, output, &prompt)? else {
+            return Ok(false);
+        };
+
+        match guesser.answer(answer) {
+            Ok(()) => {
+                if let Some(secret) = guesser.found() {
+                    let found =
+                        tr_args("guess_number.reverse.found", &[&secret, &guesser.guesses()]);
+                    writeln!(output, "{}", found)?;
+                    return Ok(true);
+                }
+            }
+            Err(contradiction) => {
+                let message = match contradiction {
+                    Contradiction::Bounds { above, below } => {
+                        tr_args("guess_number.reverse.bounds", &[&above, &below])
+                    }
+                    Contradiction::BelowRange { below, min } => {
+                        tr_args("guess_number.reverse.below_range", &[&below, &min])
+                    }
+                    Contradiction::AboveRange { above, max } => {
+                        tr_args("guess_number.reverse.above_range", &[&above, &max])
+                    }
+                    Contradiction::TooManyLies { max_lies } => {
+                        tr_args("guess_number.reverse.too_many_lies", &[&max_lies])
+                    }
+                };
+                writeln!(output, "{}", message)?;
+                return Ok(true);
+            }
+        }
+    }
+}
+
+/// Ask until the answer parses or the player types `exit`. `None` means
+/// `exit` or the end of input.
+fn read<T>(input: &mut impl BufRead, output: &mut impl Write, prompt: &str) -> io::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let prompt = format!("{} ", prompt);
+    match prompt_until_valid(input, output, &prompt, None) {
+        Ok(Command::Value(value)) => Ok(Some(value)),
+        Ok(Command::Exit) | Err(InputError::Eof) | Err(InputError::Parse { .. }) => Ok(None),
+        Err(InputError::Io(e)) => Err(e),
+    }
+}
+
+/// The answer to "play again?".
+struct YesNo(bool);
+
+impl FromStr for YesNo {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" | "是" => Ok(YesNo(true)),
+            "n" | "no" | "否" => Ok(YesNo(false)),
+            _ => Err("expected y or n".to_string()),
+        }
+    }
 }
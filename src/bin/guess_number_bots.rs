@@ -0,0 +1,186 @@
+//! Watch bots play `guess_number`, and find out which way of guessing wins.
+//!
+//! `cargo run --bin guess_number_bots` lets every bot play one game and
+//! shows its guesses. Add `--simulate` to play thousands of games instead:
+//!
+//! ```text
+//! cargo run --release --bin guess_number_bots -- --simulate --games 100000 --max 1000 --seed 1
+//! ```
+use rust_learning_group_public::{
+    guess::bots::{self, BinarySearch, LinearScan, RandomGuess, Stats, Strategy, TernarySearch},
+    random::{self, Rng},
+};
+use std::{
+    cmp::Ordering,
+    io::{self, Write},
+};
+
+/// ### Which bot wins?
+///
+/// Each guess only tells you "too small" or "too big", an `Ordering`. The
+/// binary search bot uses it to throw away half of the numbers every time,
+/// so after `k` guesses it can tell `2^k - 1` numbers apart. That is why
+/// guessing a number from 1 to 100 never takes it more than 7 tries, while
+/// the lazy bot that ignores the answers needs 50 on average.
+///
+/// Before running the simulation, guess: how does the ternary search bot,
+/// which cuts the range in three, compare?
+fn main() -> io::Result<()> {
+    let options = Options::from_args();
+    let seed = random::seed();
+    let mut rng = Rng::new(seed);
+    let range = 1..=options.max;
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(BinarySearch::default()),
+        Box::new(TernarySearch::default()),
+        Box::new(RandomGuess::new(rng.next_u64())),
+        Box::new(LinearScan::default()),
+    ];
+
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+    writeln!(
+        output,
+        "Secret numbers from 1 to {} (seed {}).",
+        options.max, seed
+    )?;
+    if options.simulate {
+        writeln!(
+            output,
+            "Binary search never needs more than ceil(log2({} + 1)) = {} guesses.\n",
+            options.max,
+            log2_bound(options.max)
+        )?;
+        for strategy in &mut strategies {
+            let stats = bots::simulate(strategy.as_mut(), range.clone(), options.games, &mut rng);
+            print_stats(&mut output, strategy.name(), &stats)?;
+        }
+    } else {
+        let secret = rng.range(range.clone());
+        writeln!(output, "The secret is {}.\n", secret)?;
+        for strategy in &mut strategies {
+            show_game(&mut output, strategy.as_mut(), options.max, secret)?;
+        }
+    }
+    Ok(())
+}
+
+struct Options {
+    simulate: bool,
+    games: usize,
+    max: i32,
+}
+
+impl Options {
+    fn from_args() -> Self {
+        let mut options = Options {
+            simulate: false,
+            games: 10_000,
+            max: 100,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--simulate" => options.simulate = true,
+                "--games" => options.games = parse_arg(args.next(), "--games"),
+                "--max" => options.max = parse_arg(args.next(), "--max"),
+                _ => {}
+            }
+        }
+        if options.games == 0 {
+            usage("--games must be at least 1");
+        }
+        if options.max < 1 {
+            usage("--max must be at least 1");
+        }
+        options
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(value: Option<String>, name: &str) -> T {
+    match value.and_then(|value| value.parse().ok()) {
+        Some(value) => value,
+        None => usage(&format!("{} needs a positive number", name)),
+    }
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("error: {}", error);
+    eprintln!("usage: guess_number_bots [--simulate] [--games N] [--max N] [--seed SEED]");
+    std::process::exit(2);
+}
+
+/// The number of guesses binary search needs at worst for `1..=max`.
+fn log2_bound(max: i32) -> u32 {
+    (max as u32 + 1).next_power_of_two().trailing_zeros()
+}
+
+/// Play one game and print every guess with the answer.
+fn show_game(
+    output: &mut impl Write,
+    strategy: &mut dyn Strategy,
+    max: i32,
+    secret: i32,
+) -> io::Result<()> {
+    strategy.start(1..=max);
+    let mut guesses = Vec::new();
+    loop {
+        let guess = strategy.next_guess();
+        let ordering = guess.cmp(&secret);
+        guesses.push(match ordering {
+            Ordering::Less => format!("{}↑", guess),
+            Ordering::Greater => format!("{}↓", guess),
+            Ordering::Equal => format!("{}✓", guess),
+        });
+        if ordering == Ordering::Equal {
+            break;
+        }
+        strategy.feedback(ordering);
+    }
+
+    writeln!(output, "{} ({} guesses):", strategy.name(), guesses.len())?;
+    const SHOWN: usize = 12;
+    if guesses.len() > SHOWN {
+        let hidden = guesses.len() - SHOWN;
+        guesses.splice(
+            SHOWN / 2..guesses.len() - SHOWN / 2,
+            [format!("... {} more ...", hidden)],
+        );
+    }
+    writeln!(output, "    {}\n", guesses.join(" "))
+}
+
+fn print_stats(output: &mut impl Write, name: &str, stats: &Stats) -> io::Result<()> {
+    writeln!(
+        output,
+        "{}: mean {:.2}, worst {} over {} games",
+        name,
+        stats.mean(),
+        stats.worst,
+        stats.games
+    )?;
+
+    // Group long tails into at most 15 rows so the table stays readable.
+    let (&first, _) = stats.distribution.first_key_value().unwrap();
+    let width = (stats.worst - first) / 15 + 1;
+    let mut rows: Vec<(u32, usize)> = Vec::new();
+    for (&guesses, &count) in &stats.distribution {
+        let start = first + (guesses - first) / width * width;
+        match rows.last_mut() {
+            Some((row, total)) if *row == start => *total += count,
+            _ => rows.push((start, count)),
+        }
+    }
+
+    let most = rows.iter().map(|&(_, count)| count).max().unwrap();
+    for (start, count) in rows {
+        let label = if width == 1 {
+            format!("{}", start)
+        } else {
+            format!("{}-{}", start, (start + width - 1).min(stats.worst))
+        };
+        let bar = "#".repeat((count * 40).div_ceil(most));
+        writeln!(output, "    {:>9} | {:<40} {}", label, bar, count)?;
+    }
+    writeln!(output)
+}
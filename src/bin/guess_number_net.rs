@@ -0,0 +1,222 @@
+//! Play `guess_number` with friends over the network.
+//!
+//! One of you hosts, everyone else joins:
+//!
+//! ```text
+//! cargo run --bin guess_number_net -- host --players 2
+//! cargo run --bin guess_number_net -- join --name alice
+//! cargo run --bin guess_number_net -- join --name bob
+//! ```
+//!
+//! The host listens on `127.0.0.1:7878` by default, so all of this works on
+//! one machine. Use `--address 0.0.0.0` on the host and `--address <host's
+//! IP>` when joining to play across a network.
+use rust_learning_group_public::{
+    guess::{
+        net::{self, ClientMessage, HostOptions, ServerMessage},
+        Command,
+    },
+    i18n::{tr, tr_args},
+    io::{prompt_until_valid, InputError},
+    random::Rng,
+};
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    time::Duration,
+};
+
+/// ### Networking with `std::net`
+///
+/// A `TcpListener` waits for connections and a `TcpStream` is one
+/// connection. A stream can be read and written just like stdin and
+/// stdout, so everything from `basic_input` and `basic_output` still
+/// applies: wrap the stream in a `BufReader` to read lines, and `writeln!`
+/// to send them.
+///
+/// The host and the players talk in a tiny text protocol, one command per
+/// line. See `src/guess/net.rs` for the full list.
+fn main() -> io::Result<()> {
+    let args = Args::parse();
+    match args.command.as_str() {
+        "host" => run_host(&args),
+        "join" => run_join(&args),
+        _ => usage(None),
+    }
+}
+
+/// Print `error`, if any, and how to use the program, then exit.
+fn usage(error: Option<&str>) -> ! {
+    if let Some(error) = error {
+        eprintln!("error: {}", error);
+    }
+    eprintln!("usage: guess_number_net host [--players N] [--timeout SECS] [--max N]");
+    eprintln!("       guess_number_net join --name NAME");
+    eprintln!("options: --address ADDRESS --port PORT --seed SEED --lang LANG");
+    std::process::exit(2);
+}
+
+struct Args {
+    command: String,
+    address: String,
+    port: u16,
+    players: usize,
+    timeout: u64,
+    max: i32,
+    name: String,
+}
+
+impl Args {
+    fn parse() -> Self {
+        let mut parsed = Args {
+            command: String::new(),
+            address: "127.0.0.1".to_string(),
+            port: net::DEFAULT_PORT,
+            players: 2,
+            timeout: 30,
+            max: 100,
+            name: String::new(),
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().unwrap_or_default();
+            match arg.as_str() {
+                "host" | "join" => parsed.command = arg,
+                "--address" => parsed.address = value(),
+                "--port" => parsed.port = number(value(), "--port"),
+                "--players" => parsed.players = number(value(), "--players"),
+                "--timeout" => parsed.timeout = number(value(), "--timeout"),
+                "--max" => parsed.max = number(value(), "--max"),
+                "--name" => parsed.name = value(),
+                _ => {}
+            }
+        }
+        if parsed.players == 0 {
+            usage(Some("--players must be at least 1"));
+        }
+        if parsed.timeout == 0 {
+            usage(Some("--timeout must be at least 1 second"));
+        }
+        if parsed.max < 1 {
+            usage(Some("--max must be at least 1"));
+        }
+        parsed
+    }
+}
+
+fn number<T: std::str::FromStr>(value: String, name: &str) -> T {
+    match value.parse() {
+        Ok(number) => number,
+        Err(_) => usage(Some(&format!("{} needs a number, not `{}`", name, value))),
+    }
+}
+
+fn run_host(args: &Args) -> io::Result<()> {
+    let listener = TcpListener::bind((args.address.as_str(), args.port))?;
+    let range = 1..=args.max;
+    let options = HostOptions {
+        players: args.players,
+        secret: Rng::from_args_or_entropy().range(range.clone()),
+        range,
+        turn_timeout: Duration::from_secs(args.timeout),
+    };
+    println!(
+        "{}",
+        tr_args(
+            "guess_number.net.hosting",
+            &[&listener.local_addr()?, &args.players]
+        )
+    );
+
+    match net::host(listener, &options)? {
+        Some(winner) => println!(
+            "{}",
+            tr_args("guess_number.net.winner", &[&winner, &options.secret])
+        ),
+        None => println!("{}", tr("guess_number.net.everyone_left")),
+    }
+    Ok(())
+}
+
+fn run_join(args: &Args) -> io::Result<()> {
+    if args.name.is_empty() || args.name.contains(char::is_whitespace) {
+        eprintln!("{}", tr("guess_number.net.need_name"));
+        std::process::exit(2);
+    }
+
+    let stream = TcpStream::connect((args.address.as_str(), args.port))?;
+    let mut server = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    writeln!(writer, "{}", ClientMessage::Name(args.name.clone()))?;
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut input = stdin.lock();
+    let mut output = stdout.lock();
+    let mut line = String::new();
+    let mut welcomed = false;
+    loop {
+        line.clear();
+        if server.read_line(&mut line)? == 0 {
+            writeln!(output, "{}", tr("guess_number.net.host_left"))?;
+            return Ok(());
+        }
+        let message = match line.parse() {
+            Ok(message) => message,
+            Err(e) => {
+                writeln!(output, "{}", e)?;
+                continue;
+            }
+        };
+
+        match message {
+            ServerMessage::Welcome {
+                min,
+                max,
+                timeout_secs,
+            } => {
+                welcomed = true;
+                let welcome = tr_args("guess_number.net.welcome", &[&min, &max, &timeout_secs]);
+                writeln!(output, "{}", welcome)?;
+                writeln!(output, "{}", tr("guess_number.prompt"))?;
+            }
+            ServerMessage::Turn => {
+                let prompt = format!("{} ", tr("guess_number.net.turn"));
+                match prompt_until_valid(&mut input, &mut output, &prompt, None) {
+                    Ok(Command::Value(guess)) => {
+                        writeln!(writer, "{}", ClientMessage::Guess(guess))?;
+                    }
+                    Ok(Command::Exit) | Err(InputError::Eof) | Err(InputError::Parse { .. }) => {
+                        return Ok(());
+                    }
+                    Err(InputError::Io(e)) => return Err(e),
+                }
+            }
+            ServerMessage::Wait(name) => {
+                writeln!(output, "{}", tr_args("guess_number.net.wait", &[&name]))?;
+            }
+            ServerMessage::Low => writeln!(output, "{}", tr("guess_number.less"))?,
+            ServerMessage::High => writeln!(output, "{}", tr("guess_number.greater"))?,
+            ServerMessage::Timeout => writeln!(output, "{}", tr("guess_number.net.timeout"))?,
+            ServerMessage::Error(text) if !welcomed => {
+                // The host did not let us in, for example because the name
+                // is taken.
+                writeln!(output, "ERROR {}", text)?;
+                std::process::exit(1);
+            }
+            ServerMessage::Error(text) => writeln!(output, "ERROR {}", text)?,
+            ServerMessage::Win(name) if name == args.name => {
+                writeln!(output, "{}", tr("guess_number.net.you_win"))?;
+                return Ok(());
+            }
+            ServerMessage::Win(name) => {
+                writeln!(
+                    output,
+                    "{}",
+                    tr_args("guess_number.net.other_wins", &[&name])
+                )?;
+                return Ok(());
+            }
+        }
+    }
+}
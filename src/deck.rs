@@ -0,0 +1,112 @@
+//! The magic deck from the UNO quiz in `control_flow_3`.
+use std::ops::Range;
+
+/// A deck that deals cards in a predictable order.
+///
+/// After each draw the seed becomes `(seed * multiplier + increment) %
+/// modulus`, and the card is the new seed modulo the number of cards,
+/// counted from the start of the card range. By default the seed starts at
+/// 0 and the deck behaves exactly like `draw_card()` in the quiz:
+/// `(seed * 71 + 3) % 100`, with cards 0 to 9.
+///
+/// ```
+/// use rust_learning_group_public::deck::MagicDeck;
+///
+/// let mut deck = MagicDeck::new();
+/// assert_eq!(deck.draw(), 3);
+/// assert_eq!(deck.draw(), 6);
+///
+/// // Clone the deck to replay the same cards.
+/// let replay = deck.clone();
+/// let next: Vec<usize> = deck.by_ref().take(3).collect();
+/// assert_eq!(replay.take(3).collect::<Vec<_>>(), next);
+/// assert_eq!(deck.draws_made(), 5);
+///
+/// let mut custom = MagicDeck::new().with_seed(7).with_cards(1..14);
+/// assert!((1..14).contains(&custom.draw()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MagicDeck {
+    seed: u64,
+    multiplier: u64,
+    increment: u64,
+    modulus: u64,
+    cards: Range<usize>,
+    draws_made: usize,
+}
+
+impl MagicDeck {
+    pub fn new() -> Self {
+        MagicDeck {
+            seed: 0,
+            multiplier: 71,
+            increment: 3,
+            modulus: 100,
+            cards: 0..10,
+            draws_made: 0,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: u64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_increment(mut self, increment: u64) -> Self {
+        self.increment = increment;
+        self
+    }
+
+    /// Panics if `modulus` is 0.
+    pub fn with_modulus(mut self, modulus: u64) -> Self {
+        assert!(modulus > 0, "the modulus of a deck must not be 0");
+        self.modulus = modulus;
+        self
+    }
+
+    /// The cards the deck deals. Panics if `cards` is empty.
+    pub fn with_cards(mut self, cards: Range<usize>) -> Self {
+        assert!(!cards.is_empty(), "a deck needs at least one kind of card");
+        self.cards = cards;
+        self
+    }
+
+    /// Draw the next card.
+    pub fn draw(&mut self) -> usize {
+        let next = (self.seed as u128 * self.multiplier as u128 + self.increment as u128)
+            % self.modulus as u128;
+        self.seed = next as u64;
+        self.draws_made += 1;
+        self.cards.start + (self.seed % self.cards.len() as u64) as usize
+    }
+
+    /// How many cards were drawn so far.
+    pub fn draws_made(&self) -> usize {
+        self.draws_made
+    }
+
+    /// The current seed, from which the next card is computed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Default for MagicDeck {
+    fn default() -> Self {
+        MagicDeck::new()
+    }
+}
+
+/// A magic deck never runs out of cards.
+impl Iterator for MagicDeck {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        Some(self.draw())
+    }
+}
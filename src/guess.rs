@@ -0,0 +1,228 @@
+//! The rules of `guess_number`, shared by the game and its variants.
+use crate::random::Rng;
+use std::{cmp::Ordering, fmt, ops::RangeInclusive, str::FromStr};
+
+pub mod bots;
+pub mod bulls;
+pub mod net;
+pub mod reverse;
+pub mod scores;
+
+/// How hard a game is: the range of the secret number and how many
+/// guesses you get.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn range(self) -> RangeInclusive<i32> {
+        match self {
+            Difficulty::Easy => 1..=50,
+            Difficulty::Normal => 1..=100,
+            Difficulty::Hard => 1..=1000,
+        }
+    }
+
+    pub fn max_attempts(self) -> u32 {
+        match self {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => 7,
+            Difficulty::Hard => 10,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    /// Accepts the name or its number: `easy`/`1`, `normal`/`2`, `hard`/`3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "easy" | "1" => Ok(Difficulty::Easy),
+            "normal" | "2" => Ok(Difficulty::Normal),
+            "hard" | "3" => Ok(Difficulty::Hard),
+            _ => Err("expected easy, normal or hard".to_string()),
+        }
+    }
+}
+
+/// A line of input that is either `exit` or a value.
+///
+/// Parse input as `Command<T>` to get the `exit` handling of
+/// `guess_number` for free, for example with
+/// [`prompt_until_valid`](crate::io::prompt_until_valid).
+///
+/// ```
+/// use rust_learning_group_public::guess::Command;
+///
+/// assert_eq!("exit".parse::<Command<i32>>(), Ok(Command::Exit));
+/// assert_eq!(" 42 ".parse::<Command<i32>>(), Ok(Command::Value(42)));
+/// assert!("forty-two".parse::<Command<i32>>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command<T> {
+    Exit,
+    Value(T),
+}
+
+impl<T: FromStr> FromStr for Command<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "exit" => Ok(Command::Exit),
+            s => s.parse().map(Command::Value),
+        }
+    }
+}
+
+/// Whether a guess got closer to the secret than the one before.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    Warmer,
+    Colder,
+    Same,
+}
+
+/// What happened after a guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    /// The secret is not the guess. `ordering` compares the guess with the
+    /// secret, so `Less` means "too small". `hint` is `None` on the first
+    /// guess.
+    Wrong {
+        ordering: Ordering,
+        hint: Option<Hint>,
+    },
+    /// The guess was wrong and it was the last attempt.
+    Lost,
+    /// The guess is outside the range. It does not count as an attempt.
+    OutOfRange,
+}
+
+/// One round of guessing.
+///
+/// ```
+/// use rust_learning_group_public::guess::{Game, Hint, Outcome};
+/// use std::cmp::Ordering;
+///
+/// let mut game = Game::with_secret(42, 1..=100, 7);
+/// assert_eq!(
+///     game.guess(50),
+///     Outcome::Wrong { ordering: Ordering::Greater, hint: None }
+/// );
+/// assert_eq!(
+///     game.guess(40),
+///     Outcome::Wrong { ordering: Ordering::Less, hint: Some(Hint::Warmer) }
+/// );
+/// assert_eq!(game.guess(0), Outcome::OutOfRange);
+/// assert_eq!(game.guess(42), Outcome::Win);
+/// assert_eq!(game.attempts(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Game {
+    secret: i32,
+    range: RangeInclusive<i32>,
+    max_attempts: u32,
+    /// Guesses in range, in the order they were made.
+    history: Vec<i32>,
+    last_distance: Option<u32>,
+    finished: bool,
+}
+
+impl Game {
+    /// A game with a secret drawn from `rng`.
+    pub fn new(difficulty: Difficulty, rng: &mut Rng) -> Self {
+        let range = difficulty.range();
+        let secret = rng.range(range.clone());
+        Game::with_secret(secret, range, difficulty.max_attempts())
+    }
+
+    /// A game with a known secret, for tests and replays.
+    pub fn with_secret(secret: i32, range: RangeInclusive<i32>, max_attempts: u32) -> Self {
+        Game {
+            secret,
+            range,
+            max_attempts,
+            history: Vec::new(),
+            last_distance: None,
+            finished: false,
+        }
+    }
+
+    /// Panics if the game is already won or lost.
+    pub fn guess(&mut self, guess: i32) -> Outcome {
+        assert!(!self.finished, "the game is over");
+        if !self.range.contains(&guess) {
+            return Outcome::OutOfRange;
+        }
+        self.history.push(guess);
+
+        let ordering = guess.cmp(&self.secret);
+        if ordering == Ordering::Equal {
+            self.finished = true;
+            return Outcome::Win;
+        }
+        if self.attempts() >= self.max_attempts {
+            self.finished = true;
+            return Outcome::Lost;
+        }
+
+        let distance = guess.abs_diff(self.secret);
+        let hint = self.last_distance.map(|last| match distance.cmp(&last) {
+            Ordering::Less => Hint::Warmer,
+            Ordering::Greater => Hint::Colder,
+            Ordering::Equal => Hint::Same,
+        });
+        self.last_distance = Some(distance);
+        Outcome::Wrong { ordering, hint }
+    }
+
+    /// Guesses made so far, not counting those out of range.
+    pub fn attempts(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    pub fn attempts_left(&self) -> u32 {
+        self.max_attempts - self.attempts()
+    }
+
+    /// The guesses made so far, not counting those out of range.
+    pub fn history(&self) -> &[i32] {
+        &self.history
+    }
+
+    pub fn range(&self) -> RangeInclusive<i32> {
+        self.range.clone()
+    }
+
+    /// Only look at this once the game is over!
+    pub fn secret(&self) -> i32 {
+        self.secret
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.history.last() == Some(&self.secret)
+    }
+}
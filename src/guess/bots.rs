@@ -0,0 +1,228 @@
+//! Bots that play `guess_number` using nothing but its feedback.
+//!
+//! Every bot only learns whether its guess was too small or too big, just
+//! like a human player. Comparing them shows why halving the range is the
+//! best you can do: after `k` guesses binary search has told apart
+//! `2^k - 1` numbers, so `ceil(log2(n + 1))` guesses always suffice.
+use super::{Game, Outcome};
+use crate::random::Rng;
+use std::{cmp::Ordering, collections::BTreeMap, ops::RangeInclusive};
+
+/// A way of playing `guess_number`.
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    /// Forget the last game and start guessing in `range`.
+    fn start(&mut self, range: RangeInclusive<i32>);
+
+    fn next_guess(&mut self) -> i32;
+
+    /// How the last guess compares with the secret: `Less` means the
+    /// guess was too small.
+    fn feedback(&mut self, ordering: Ordering);
+}
+
+/// Always guess the middle of what is left.
+#[derive(Default)]
+pub struct BinarySearch {
+    low: i32,
+    high: i32,
+    last: i32,
+}
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &'static str {
+        "binary search"
+    }
+
+    fn start(&mut self, range: RangeInclusive<i32>) {
+        (self.low, self.high) = range.into_inner();
+    }
+
+    fn next_guess(&mut self) -> i32 {
+        self.last = self.low + (self.high - self.low) / 2;
+        self.last
+    }
+
+    fn feedback(&mut self, ordering: Ordering) {
+        narrow(&mut self.low, &mut self.high, self.last, ordering);
+    }
+}
+
+/// Guess at one third of what is left, then at two thirds if the secret
+/// is above the first point.
+#[derive(Default)]
+pub struct TernarySearch {
+    low: i32,
+    high: i32,
+    last: i32,
+    /// The upper probe to try next, if the lower one was too small.
+    upper: Option<i32>,
+}
+
+impl Strategy for TernarySearch {
+    fn name(&self) -> &'static str {
+        "ternary search"
+    }
+
+    fn start(&mut self, range: RangeInclusive<i32>) {
+        (self.low, self.high) = range.into_inner();
+        self.upper = None;
+    }
+
+    fn next_guess(&mut self) -> i32 {
+        let third = (self.high - self.low) / 3;
+        self.last = match self.upper.take() {
+            Some(upper) if (self.low..=self.high).contains(&upper) => upper,
+            _ => {
+                self.upper = Some(self.high - third);
+                self.low + third
+            }
+        };
+        self.last
+    }
+
+    fn feedback(&mut self, ordering: Ordering) {
+        if ordering == Ordering::Greater {
+            self.upper = None;
+        }
+        narrow(&mut self.low, &mut self.high, self.last, ordering);
+    }
+}
+
+/// Guess any number that is still possible.
+pub struct RandomGuess {
+    rng: Rng,
+    low: i32,
+    high: i32,
+    last: i32,
+}
+
+impl RandomGuess {
+    pub fn new(seed: u64) -> Self {
+        RandomGuess {
+            rng: Rng::new(seed),
+            low: 0,
+            high: 0,
+            last: 0,
+        }
+    }
+}
+
+impl Strategy for RandomGuess {
+    fn name(&self) -> &'static str {
+        "random guessing"
+    }
+
+    fn start(&mut self, range: RangeInclusive<i32>) {
+        (self.low, self.high) = range.into_inner();
+    }
+
+    fn next_guess(&mut self) -> i32 {
+        self.last = self.rng.range(self.low..=self.high);
+        self.last
+    }
+
+    fn feedback(&mut self, ordering: Ordering) {
+        narrow(&mut self.low, &mut self.high, self.last, ordering);
+    }
+}
+
+/// Try every number from the smallest up, ignoring the feedback.
+#[derive(Default)]
+pub struct LinearScan {
+    next: i32,
+}
+
+impl Strategy for LinearScan {
+    fn name(&self) -> &'static str {
+        "lazy linear scan"
+    }
+
+    fn start(&mut self, range: RangeInclusive<i32>) {
+        self.next = *range.start();
+    }
+
+    fn next_guess(&mut self) -> i32 {
+        self.next += 1;
+        self.next - 1
+    }
+
+    fn feedback(&mut self, _: Ordering) {}
+}
+
+/// Shrink `low..=high` after `guess` compared as `ordering` with the secret.
+fn narrow(low: &mut i32, high: &mut i32, guess: i32, ordering: Ordering) {
+    match ordering {
+        Ordering::Less => *low = guess + 1,
+        Ordering::Greater => *high = guess - 1,
+        Ordering::Equal => {}
+    }
+}
+
+/// Let `strategy` play until it finds `secret`, and return the number of
+/// guesses it needed.
+///
+/// ```
+/// use rust_learning_group_public::guess::bots::{play, BinarySearch, LinearScan};
+///
+/// assert_eq!(play(&mut BinarySearch::default(), 1..=100, 50), 1);
+/// assert_eq!(play(&mut LinearScan::default(), 1..=100, 42), 42);
+/// for secret in 1..=100 {
+///     assert!(play(&mut BinarySearch::default(), 1..=100, secret) <= 7);
+/// }
+/// ```
+///
+/// Panics if the strategy guesses outside `range`.
+pub fn play(strategy: &mut dyn Strategy, range: RangeInclusive<i32>, secret: i32) -> u32 {
+    strategy.start(range.clone());
+    let mut game = Game::with_secret(secret, range, u32::MAX);
+    loop {
+        let guess = strategy.next_guess();
+        match game.guess(guess) {
+            Outcome::Win => return game.attempts(),
+            Outcome::Wrong { ordering, .. } => strategy.feedback(ordering),
+            Outcome::OutOfRange => panic!("{} guessed {} out of range", strategy.name(), guess),
+            Outcome::Lost => unreachable!("bots have unlimited attempts"),
+        }
+    }
+}
+
+/// How many guesses a strategy needed over many games.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    pub games: usize,
+    pub total: u64,
+    pub worst: u32,
+    /// Number of games won with each number of guesses.
+    pub distribution: BTreeMap<u32, usize>,
+}
+
+impl Stats {
+    /// The average number of guesses, 0 if no games were played.
+    pub fn mean(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        self.total as f64 / self.games as f64
+    }
+}
+
+/// Play `games` games with secrets drawn from `rng`.
+pub fn simulate(
+    strategy: &mut dyn Strategy,
+    range: RangeInclusive<i32>,
+    games: usize,
+    rng: &mut Rng,
+) -> Stats {
+    let mut stats = Stats::default();
+    for _ in 0..games {
+        let secret = rng.range(range.clone());
+        let guesses = play(strategy, range.clone(), secret);
+        stats.games += 1;
+        stats.total += guesses as u64;
+        stats.worst = stats.worst.max(guesses);
+        *stats.distribution.entry(guesses).or_default() += 1;
+    }
+    stats
+}
@@ -0,0 +1,220 @@
+//! Bulls and Cows: guess a 4-digit code with distinct digits.
+//!
+//! Instead of "too small" or "too big" every guess is answered with
+//! `xA yB`: `x` digits are right and in the right place (bulls), `y` more
+//! digits are in the code but somewhere else (cows).
+//!
+//! ```
+//! use rust_learning_group_public::guess::bulls::{Code, Feedback};
+//!
+//! let secret: Code = "1234".parse().unwrap();
+//! let feedback = secret.compare("1325".parse().unwrap());
+//! assert_eq!(feedback, Feedback { bulls: 1, cows: 2 });
+//! assert_eq!(feedback.to_string(), "1A2B");
+//! ```
+use super::bots::Stats;
+use crate::random::Rng;
+use std::{fmt, str::FromStr};
+
+/// How many digits a code has.
+pub const DIGITS: usize = 4;
+
+/// A code of [`DIGITS`] different digits, for example `0482`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Code([u8; DIGITS]);
+
+impl Code {
+    /// Every possible code, from `0123` to `9876`. There are
+    /// `10 * 9 * 8 * 7 = 5040` of them.
+    pub fn all() -> Vec<Code> {
+        let mut codes = Vec::new();
+        for n in 0..10_usize.pow(DIGITS as u32) {
+            let mut digits = [0; DIGITS];
+            let mut rest = n;
+            for digit in digits.iter_mut().rev() {
+                *digit = (rest % 10) as u8;
+                rest /= 10;
+            }
+            if let Ok(code) = Code::new(digits) {
+                codes.push(code);
+            }
+        }
+        codes
+    }
+
+    /// A code from its digits, if they are all different and below 10.
+    pub fn new(digits: [u8; DIGITS]) -> Result<Code, String> {
+        for (i, &digit) in digits.iter().enumerate() {
+            if digit > 9 {
+                return Err(format!("{} is not a digit", digit));
+            }
+            if digits[..i].contains(&digit) {
+                return Err(format!("the digit {} appears twice", digit));
+            }
+        }
+        Ok(Code(digits))
+    }
+
+    pub fn random(rng: &mut Rng) -> Code {
+        let digits: Vec<u8> = (0..10).collect();
+        let mut code = [0; DIGITS];
+        code.copy_from_slice(&rng.sample(&digits, DIGITS));
+        Code(code)
+    }
+
+    pub fn digits(&self) -> [u8; DIGITS] {
+        self.0
+    }
+
+    /// The answer to `guess` if `self` is the secret.
+    pub fn compare(&self, guess: Code) -> Feedback {
+        let mut feedback = Feedback { bulls: 0, cows: 0 };
+        for (i, digit) in guess.0.iter().enumerate() {
+            if self.0[i] == *digit {
+                feedback.bulls += 1;
+            } else if self.0.contains(digit) {
+                feedback.cows += 1;
+            }
+        }
+        feedback
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for digit in self.0 {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Code {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.chars().count() != DIGITS {
+            return Err(format!("a code has {} digits", DIGITS));
+        }
+        let mut digits = [0; DIGITS];
+        for (digit, c) in digits.iter_mut().zip(s.chars()) {
+            match c.to_digit(10) {
+                Some(d) => *digit = d as u8,
+                None => return Err(format!("`{}` is not a digit", c)),
+            }
+        }
+        Code::new(digits)
+    }
+}
+
+/// The answer to a guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Feedback {
+    /// Right digit in the right place.
+    pub bulls: u32,
+    /// Right digit in the wrong place.
+    pub cows: u32,
+}
+
+impl Feedback {
+    pub fn is_win(&self) -> bool {
+        self.bulls == DIGITS as u32
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}A{}B", self.bulls, self.cows)
+    }
+}
+
+/// A bot that always guesses a code which could still be the secret.
+///
+/// It keeps every code that would have given the same answers to all
+/// guesses so far, and guesses the first of them. That is not the best
+/// possible play, but it never wastes a guess on a code it knows is wrong.
+///
+/// ```
+/// use rust_learning_group_public::guess::bulls::{play, Code, Solver};
+///
+/// let secret: Code = "9876".parse().unwrap();
+/// assert!(play(&mut Solver::new(), secret) <= 7);
+/// ```
+pub struct Solver {
+    candidates: Vec<Code>,
+    last: Option<Code>,
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Solver::with_candidates(Code::all())
+    }
+
+    /// A solver that starts from `candidates`, for example a copy of
+    /// [`Code::all`] made once for many games.
+    pub fn with_candidates(candidates: Vec<Code>) -> Self {
+        Solver {
+            candidates,
+            last: None,
+        }
+    }
+
+    /// The codes that are still possible.
+    pub fn candidates(&self) -> &[Code] {
+        &self.candidates
+    }
+
+    /// `None` if the answers so far contradict each other.
+    pub fn next_guess(&mut self) -> Option<Code> {
+        self.last = self.candidates.first().copied();
+        self.last
+    }
+
+    /// Throw away every code that would not have answered the last guess
+    /// with `feedback`.
+    pub fn feedback(&mut self, feedback: Feedback) {
+        if let Some(guess) = self.last {
+            self.candidates
+                .retain(|candidate| candidate.compare(guess) == feedback);
+        }
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Solver::new()
+    }
+}
+
+/// Let `solver`, which has not guessed yet, find `secret` and return the
+/// number of guesses.
+pub fn play(solver: &mut Solver, secret: Code) -> u32 {
+    let mut guesses = 0;
+    loop {
+        let guess = solver
+            .next_guess()
+            .expect("the secret is always a candidate");
+        guesses += 1;
+        let feedback = secret.compare(guess);
+        if feedback.is_win() {
+            return guesses;
+        }
+        solver.feedback(feedback);
+    }
+}
+
+/// Play against every possible secret, so the result is exact rather than
+/// an estimate.
+pub fn benchmark() -> Stats {
+    let mut stats = Stats::default();
+    let all = Code::all();
+    for &secret in &all {
+        let guesses = play(&mut Solver::with_candidates(all.clone()), secret);
+        stats.games += 1;
+        stats.total += guesses as u64;
+        stats.worst = stats.worst.max(guesses);
+        *stats.distribution.entry(guesses).or_default() += 1;
+    }
+    stats
+}
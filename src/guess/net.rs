@@ -0,0 +1,534 @@
+//! Multiplayer `guess_number` over TCP, with `std::net` only.
+//!
+//! One player hosts a game with a secret number and the others connect
+//! and take turns guessing. Everything is plain text, one message per
+//! line, so you can even play with `nc localhost 7878`.
+//!
+//! ### Protocol
+//!
+//! | client → server | meaning                             |
+//! |-----------------|-------------------------------------|
+//! | `NAME alice`    | my name, sent once after connecting |
+//! | `GUESS 42`      | my guess, only after `TURN`         |
+//!
+//! | server → client    | meaning                                        |
+//! |--------------------|------------------------------------------------|
+//! | `WELCOME 1 100 30` | the secret is in 1..=100, a turn lasts 30 s    |
+//! | `TURN`             | your turn, send a `GUESS`                      |
+//! | `WAIT bob`         | it is bob's turn                               |
+//! | `LOW` / `HIGH`     | your guess was too low / too high              |
+//! | `TIMEOUT`          | you did not guess in time, your turn is over   |
+//! | `ERROR <text>`     | the last line made no sense, your turn is over |
+//! | `WIN alice`        | alice found the number, the game is over       |
+//!
+//! Names are unique: a `NAME` that is already taken is answered with
+//! `ERROR name taken` and the connection is closed.
+//!
+//! Once the expected number of players has joined, the server gives turns
+//! in the order they connected until someone wins or everyone has left.
+use std::{
+    cmp::Ordering,
+    fmt,
+    io::{self, BufRead, BufReader, ErrorKind, Write},
+    net::{TcpListener, TcpStream},
+    ops::RangeInclusive,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// A line sent by a player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientMessage {
+    Name(String),
+    Guess(i32),
+}
+
+/// A line sent by the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServerMessage {
+    Welcome {
+        min: i32,
+        max: i32,
+        timeout_secs: u64,
+    },
+    Turn,
+    Wait(String),
+    Low,
+    High,
+    Timeout,
+    Error(String),
+    Win(String),
+}
+
+impl fmt::Display for ClientMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientMessage::Name(name) => write!(f, "NAME {}", name),
+            ClientMessage::Guess(guess) => write!(f, "GUESS {}", guess),
+        }
+    }
+}
+
+impl FromStr for ClientMessage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, argument) = split_command(s);
+        match command {
+            "NAME" if is_valid_name(argument) => Ok(ClientMessage::Name(argument.to_string())),
+            "NAME" => Err("a name is one word".to_string()),
+            "GUESS" => argument
+                .parse()
+                .map(ClientMessage::Guess)
+                .map_err(|_| format!("`{}` is not a number", argument)),
+            _ => Err(format!("unknown command `{}`", command)),
+        }
+    }
+}
+
+impl fmt::Display for ServerMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServerMessage::Welcome {
+                min,
+                max,
+                timeout_secs,
+            } => write!(f, "WELCOME {} {} {}", min, max, timeout_secs),
+            ServerMessage::Turn => write!(f, "TURN"),
+            ServerMessage::Wait(name) => write!(f, "WAIT {}", name),
+            ServerMessage::Low => write!(f, "LOW"),
+            ServerMessage::High => write!(f, "HIGH"),
+            ServerMessage::Timeout => write!(f, "TIMEOUT"),
+            ServerMessage::Error(text) => write!(f, "ERROR {}", text),
+            ServerMessage::Win(name) => write!(f, "WIN {}", name),
+        }
+    }
+}
+
+impl FromStr for ServerMessage {
+    type Err = String;
+
+    /// ```
+    /// use rust_learning_group_public::guess::net::ServerMessage;
+    ///
+    /// let welcome = ServerMessage::Welcome { min: 1, max: 100, timeout_secs: 30 };
+    /// assert_eq!(welcome.to_string().parse(), Ok(welcome));
+    /// assert_eq!("WIN alice".parse(), Ok(ServerMessage::Win("alice".to_string())));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, argument) = split_command(s);
+        let message = match command {
+            "WELCOME" => {
+                let numbers: Vec<&str> = argument.split_whitespace().collect();
+                match numbers[..] {
+                    [min, max, timeout] => ServerMessage::Welcome {
+                        min: min.parse().map_err(|_| "bad WELCOME")?,
+                        max: max.parse().map_err(|_| "bad WELCOME")?,
+                        timeout_secs: timeout.parse().map_err(|_| "bad WELCOME")?,
+                    },
+                    _ => return Err("bad WELCOME".to_string()),
+                }
+            }
+            "TURN" => ServerMessage::Turn,
+            "WAIT" => ServerMessage::Wait(argument.to_string()),
+            "LOW" => ServerMessage::Low,
+            "HIGH" => ServerMessage::High,
+            "TIMEOUT" => ServerMessage::Timeout,
+            "ERROR" => ServerMessage::Error(argument.to_string()),
+            "WIN" => ServerMessage::Win(argument.to_string()),
+            _ => return Err(format!("unknown command `{}`", command)),
+        };
+        Ok(message)
+    }
+}
+
+fn split_command(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(' ') {
+        Some((command, argument)) => (command, argument.trim()),
+        None => (line, ""),
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(char::is_whitespace)
+}
+
+/// A connected player, as seen by the host.
+struct Player {
+    name: String,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    /// The start of a line that was cut off by a timeout.
+    partial: Vec<u8>,
+}
+
+impl Player {
+    fn send(&mut self, message: &ServerMessage) -> io::Result<()> {
+        writeln!(self.writer, "{}", message)
+    }
+
+    /// The next line, `Ok(None)` if the player left, or a `TimedOut` error
+    /// once `deadline` has passed. A socket timeout only limits a single
+    /// read, so it is set again before every read: otherwise a player who
+    /// sends one byte at a time could keep their turn forever. The part of
+    /// a line read before a timeout is kept for the next call.
+    fn receive(&mut self, deadline: Instant) -> io::Result<Option<String>> {
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Err(io::Error::new(ErrorKind::TimedOut, "the turn is over"));
+            }
+            self.reader.get_ref().set_read_timeout(Some(left))?;
+            let buffer = self.reader.fill_buf()?;
+            if buffer.is_empty() {
+                // The player left, maybe in the middle of a line.
+                return Ok(None);
+            }
+            let (len, complete) = match buffer.iter().position(|&b| b == b'\n') {
+                Some(end) => (end + 1, true),
+                None => (buffer.len(), false),
+            };
+            self.partial.extend_from_slice(&buffer[..len]);
+            self.reader.consume(len);
+            if complete {
+                let line = String::from_utf8_lossy(&self.partial).into_owned();
+                self.partial.clear();
+                return Ok(Some(line));
+            }
+        }
+    }
+
+    /// Throw away everything the player sent so far, such as a guess that
+    /// came too late or out of turn, so it is not taken as the answer to
+    /// the next `TURN`.
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.partial.clear();
+        self.reader.get_ref().set_nonblocking(true)?;
+        let drained = loop {
+            match self.reader.fill_buf() {
+                Ok([]) => break Ok(()),
+                Ok(buffer) => {
+                    let len = buffer.len();
+                    self.reader.consume(len);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break Ok(()),
+                Err(e) => break Err(e),
+            }
+        };
+        self.reader.get_ref().set_nonblocking(false)?;
+        drained
+    }
+}
+
+/// Whether `error` only means that this player is gone or too slow, as
+/// opposed to a problem with the host itself.
+fn is_player_error(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::WouldBlock
+            | ErrorKind::TimedOut
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+            | ErrorKind::UnexpectedEof
+    )
+}
+
+/// Settings for [`host`].
+#[derive(Clone, Debug)]
+pub struct HostOptions {
+    pub players: usize,
+    pub range: RangeInclusive<i32>,
+    pub secret: i32,
+    pub turn_timeout: Duration,
+}
+
+/// Wait for `options.players` players on `listener`, then run one game.
+///
+/// Returns the winner's name, or `None` if everyone left first. A zero
+/// `turn_timeout` is an `InvalidInput` error.
+///
+/// ```
+/// use rust_learning_group_public::guess::net::{host, HostOptions};
+/// use std::{
+///     io::{BufRead, BufReader, Write},
+///     net::{TcpListener, TcpStream},
+///     thread,
+///     time::Duration,
+/// };
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let address = listener.local_addr().unwrap();
+/// let options = HostOptions {
+///     players: 1,
+///     range: 1..=100,
+///     secret: 42,
+///     turn_timeout: Duration::from_secs(5),
+/// };
+/// let server = thread::spawn(move || host(listener, &options).unwrap());
+///
+/// let stream = TcpStream::connect(address).unwrap();
+/// let mut reader = BufReader::new(stream.try_clone().unwrap());
+/// let mut writer = stream;
+/// let mut receive = || {
+///     let mut line = String::new();
+///     reader.read_line(&mut line).unwrap();
+///     line.trim().to_string()
+/// };
+///
+/// writeln!(writer, "NAME alice").unwrap();
+/// assert_eq!(receive(), "WELCOME 1 100 5");
+/// assert_eq!(receive(), "TURN");
+/// writeln!(writer, "GUESS 50").unwrap();
+/// assert_eq!(receive(), "HIGH");
+/// assert_eq!(receive(), "TURN");
+/// writeln!(writer, "GUESS 42").unwrap();
+/// assert_eq!(receive(), "WIN alice");
+/// assert_eq!(server.join().unwrap(), Some("alice".to_string()));
+/// ```
+pub fn host(listener: TcpListener, options: &HostOptions) -> io::Result<Option<String>> {
+    if options.turn_timeout.is_zero() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            "the turn timeout must be longer than zero",
+        ));
+    }
+
+    let mut players = Vec::new();
+    while players.len() < options.players {
+        let (stream, _) = listener.accept()?;
+        match join(stream, options, &players) {
+            Ok(Some(player)) => players.push(player),
+            // A client that cannot introduce itself is not a player.
+            Ok(None) => {}
+            Err(e) if is_player_error(&e) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut turn = 0;
+    while !players.is_empty() {
+        turn %= players.len();
+        let current = players[turn].name.clone();
+        // Anything sent before this `TURN`, like a guess that came too late,
+        // is not an answer to it.
+        let _ = players[turn].discard_pending();
+        for (i, player) in players.iter_mut().enumerate() {
+            let message = if i == turn {
+                ServerMessage::Turn
+            } else {
+                ServerMessage::Wait(current.clone())
+            };
+            // A player who left is noticed when it is their turn.
+            let _ = player.send(&message);
+        }
+
+        let deadline = Instant::now() + options.turn_timeout;
+        let reply = match players[turn].receive(deadline) {
+            Ok(None) => {
+                players.remove(turn);
+                continue;
+            }
+            Ok(Some(line)) => match line.parse() {
+                Ok(ClientMessage::Guess(guess)) => match guess.cmp(&options.secret) {
+                    Ordering::Less => ServerMessage::Low,
+                    Ordering::Greater => ServerMessage::High,
+                    Ordering::Equal => {
+                        for player in &mut players {
+                            let _ = player.send(&ServerMessage::Win(current.clone()));
+                        }
+                        return Ok(Some(current));
+                    }
+                },
+                Ok(ClientMessage::Name(_)) => ServerMessage::Error("already named".to_string()),
+                Err(e) => ServerMessage::Error(e),
+            },
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                ServerMessage::Timeout
+            }
+            Err(_) => {
+                players.remove(turn);
+                continue;
+            }
+        };
+        if players[turn].send(&reply).is_err() {
+            players.remove(turn);
+            continue;
+        }
+        turn += 1;
+    }
+    Ok(None)
+}
+
+/// Read the `NAME` line of a new connection and welcome the player, unless
+/// one of the `players` already has that name.
+fn join(
+    stream: TcpStream,
+    options: &HostOptions,
+    players: &[Player],
+) -> io::Result<Option<Player>> {
+    let mut player = Player {
+        name: String::new(),
+        reader: BufReader::new(stream.try_clone()?),
+        writer: stream,
+        partial: Vec::new(),
+    };
+    let Some(line) = player.receive(Instant::now() + options.turn_timeout)? else {
+        return Ok(None);
+    };
+    match line.parse() {
+        Ok(ClientMessage::Name(name)) if players.iter().any(|p| p.name == name) => {
+            player.send(&ServerMessage::Error("name taken".to_string()))?;
+            Ok(None)
+        }
+        Ok(ClientMessage::Name(name)) => {
+            player.name = name;
+            player.send(&ServerMessage::Welcome {
+                min: *options.range.start(),
+                max: *options.range.end(),
+                timeout_secs: options.turn_timeout.as_secs(),
+            })?;
+            Ok(Some(player))
+        }
+        _ => {
+            player.send(&ServerMessage::Error("send NAME first".to_string()))?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{host, HostOptions};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
+    };
+
+    const TURN: Duration = Duration::from_millis(500);
+
+    /// Host a game for `players` players with the secret 42.
+    fn start(players: usize) -> (String, JoinHandle<Option<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let options = HostOptions {
+            players,
+            range: 1..=100,
+            secret: 42,
+            turn_timeout: TURN,
+        };
+        let server = thread::spawn(move || host(listener, &options).unwrap());
+        (address, server)
+    }
+
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    }
+
+    impl Client {
+        fn join(address: &str, name: &str) -> Client {
+            let stream = TcpStream::connect(address).unwrap();
+            let mut client = Client {
+                reader: BufReader::new(stream.try_clone().unwrap()),
+                writer: stream,
+            };
+            client.send(&format!("NAME {}\n", name));
+            client
+        }
+
+        fn send(&mut self, text: &str) {
+            self.writer.write_all(text.as_bytes()).unwrap();
+        }
+
+        fn receive(&mut self) -> String {
+            let mut line = String::new();
+            self.reader.read_line(&mut line).unwrap();
+            line.trim().to_string()
+        }
+    }
+
+    #[test]
+    fn trickling_bytes_does_not_keep_the_turn() {
+        let (address, server) = start(2);
+        let mut alice = Client::join(&address, "alice");
+        assert!(alice.receive().starts_with("WELCOME"));
+        let mut bob = Client::join(&address, "bob");
+        assert!(bob.receive().starts_with("WELCOME"));
+        assert_eq!(alice.receive(), "TURN");
+
+        // One byte every 100 ms, never a whole line, for longer than a turn.
+        let mut writer = alice.writer.try_clone().unwrap();
+        let started = Instant::now();
+        let trickle = thread::spawn(move || {
+            for _ in 0..8 {
+                writer.write_all(b"1").unwrap();
+                thread::sleep(TURN / 5);
+            }
+        });
+        assert_eq!(alice.receive(), "TIMEOUT");
+        assert!(started.elapsed() < TURN * 3 / 2);
+        trickle.join().unwrap();
+
+        // The trickled bytes are not part of alice's next guess.
+        assert_eq!(bob.receive(), "WAIT alice");
+        assert_eq!(bob.receive(), "TURN");
+        bob.send("GUESS 100\n");
+        assert_eq!(bob.receive(), "HIGH");
+        assert_eq!(alice.receive(), "WAIT bob");
+        assert_eq!(alice.receive(), "TURN");
+        alice.send("GUESS 42\n");
+        assert_eq!(alice.receive(), "WIN alice");
+        assert_eq!(server.join().unwrap(), Some("alice".to_string()));
+    }
+
+    #[test]
+    fn a_late_guess_does_not_answer_the_next_turn() {
+        let (address, server) = start(2);
+        let mut alice = Client::join(&address, "alice");
+        assert!(alice.receive().starts_with("WELCOME"));
+        let mut bob = Client::join(&address, "bob");
+        assert!(bob.receive().starts_with("WELCOME"));
+
+        assert_eq!(alice.receive(), "TURN");
+        assert_eq!(bob.receive(), "WAIT alice");
+        assert_eq!(alice.receive(), "TIMEOUT");
+        alice.send("GUESS 1\n");
+
+        assert_eq!(bob.receive(), "TURN");
+        assert_eq!(alice.receive(), "WAIT bob");
+        thread::sleep(TURN / 5);
+        bob.send("GUESS 100\n");
+        assert_eq!(bob.receive(), "HIGH");
+
+        assert_eq!(alice.receive(), "TURN");
+        alice.send("GUESS 99\n");
+        assert_eq!(alice.receive(), "HIGH");
+        assert_eq!(alice.receive(), "WAIT bob");
+        drop((alice, bob));
+        assert_eq!(server.join().unwrap(), None);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let (address, server) = start(2);
+        let mut alice = Client::join(&address, "alice");
+        assert!(alice.receive().starts_with("WELCOME"));
+        let mut other = Client::join(&address, "alice");
+        assert_eq!(other.receive(), "ERROR name taken");
+        let mut bob = Client::join(&address, "bob");
+        assert!(bob.receive().starts_with("WELCOME"));
+
+        assert_eq!(alice.receive(), "TURN");
+        alice.send("GUESS 42\n");
+        assert_eq!(alice.receive(), "WIN alice");
+        assert_eq!(bob.receive(), "WAIT alice");
+        assert_eq!(bob.receive(), "WIN alice");
+        assert_eq!(server.join().unwrap(), Some("alice".to_string()));
+    }
+}
@@ -0,0 +1,227 @@
+//! `guess_number` the other way round: you pick, the computer guesses.
+//!
+//! The guesser keeps track of every number that still fits your answers
+//! and notices when nothing does. It can also play Ulam's game, where you
+//! may lie a few times: a number stays possible as long as it contradicts
+//! at most that many answers.
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+/// What the player says about the computer's guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// The secret is bigger than the guess.
+    Bigger,
+    /// The secret is smaller than the guess.
+    Smaller,
+    Correct,
+}
+
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bigger" | "b" | ">" | "大" => Ok(Answer::Bigger),
+            "smaller" | "s" | "<" | "小" => Ok(Answer::Smaller),
+            "correct" | "c" | "=" | "对" => Ok(Answer::Correct),
+            _ => Err("expected bigger, smaller or correct".to_string()),
+        }
+    }
+}
+
+/// The answers cannot all be true.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Contradiction {
+    /// "Bigger than `above`" and "smaller than `below`" leave no number.
+    Bounds { above: i32, below: i32 },
+    /// "Smaller than `below`", but the range starts at `min`.
+    BelowRange { below: i32, min: i32 },
+    /// "Bigger than `above`", but the range ends at `max`.
+    AboveRange { above: i32, max: i32 },
+    /// Every number contradicts more than `max_lies` answers.
+    TooManyLies { max_lies: u32 },
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Contradiction::Bounds { above, below } => write!(
+                f,
+                "you said bigger than {} and smaller than {}",
+                above, below
+            ),
+            Contradiction::BelowRange { below, min } => write!(
+                f,
+                "you said smaller than {}, but the number is at least {}",
+                below, min
+            ),
+            Contradiction::AboveRange { above, max } => write!(
+                f,
+                "you said bigger than {}, but the number is at most {}",
+                above, max
+            ),
+            Contradiction::TooManyLies { max_lies } => {
+                write!(f, "more than {} of your answers were lies", max_lies)
+            }
+        }
+    }
+}
+
+/// The computer's side of the reverse game.
+///
+/// ```
+/// use rust_learning_group_public::guess::reverse::{Answer, Contradiction, Guesser};
+///
+/// let mut guesser = Guesser::new(1..=100, 0);
+/// let answers = [
+///     (50, Answer::Bigger),
+///     (75, Answer::Smaller),
+///     (62, Answer::Smaller),
+///     (56, Answer::Bigger),
+///     (59, Answer::Smaller),
+/// ];
+/// for (guess, answer) in answers {
+///     assert_eq!(guesser.next_guess(), guess);
+///     guesser.answer(answer).unwrap();
+/// }
+/// assert_eq!(guesser.next_guess(), 57);
+/// assert_eq!(
+///     guesser.answer(Answer::Smaller),
+///     Err(Contradiction::Bounds { above: 56, below: 57 })
+/// );
+/// ```
+///
+/// Allowing one lie, the guesser still finds the number:
+///
+/// ```
+/// use rust_learning_group_public::guess::reverse::{Answer, Guesser};
+/// use std::cmp::Ordering;
+///
+/// let secret = 7;
+/// let mut guesser = Guesser::new(1..=10, 1);
+/// let mut lied = false;
+/// while guesser.found().is_none() {
+///     let guess = guesser.next_guess();
+///     let answer = match (secret.cmp(&guess), lied) {
+///         (Ordering::Equal, _) => Answer::Correct,
+///         (Ordering::Greater, false) | (Ordering::Less, true) => Answer::Smaller,
+///         (Ordering::Less, false) | (Ordering::Greater, true) => Answer::Bigger,
+///     };
+///     lied = true;
+///     guesser.answer(answer).unwrap();
+/// }
+/// assert_eq!(guesser.found(), Some(7));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Guesser {
+    range: RangeInclusive<i32>,
+    max_lies: u32,
+    /// For each number in the range, how many answers it contradicts.
+    lies: Vec<u32>,
+    /// The largest guess answered with "bigger" and the smallest answered
+    /// with "smaller", to explain contradictions.
+    above: Option<i32>,
+    below: Option<i32>,
+    last_guess: Option<i32>,
+    guesses: u32,
+    found: Option<i32>,
+}
+
+impl Guesser {
+    /// Panics if `range` is empty.
+    pub fn new(range: RangeInclusive<i32>, max_lies: u32) -> Self {
+        assert!(!range.is_empty(), "cannot guess in an empty range");
+        let len = (*range.end() as i64 - *range.start() as i64 + 1) as usize;
+        Guesser {
+            range,
+            max_lies,
+            lies: vec![0; len],
+            above: None,
+            below: None,
+            last_guess: None,
+            guesses: 0,
+            found: None,
+        }
+    }
+
+    /// The next number to ask about.
+    ///
+    /// Each possible number is weighted by how many more lies it could
+    /// still absorb, and the guess splits that weight in half. Without
+    /// lies this is plain binary search.
+    pub fn next_guess(&mut self) -> i32 {
+        let weights = || {
+            self.lies
+                .iter()
+                .enumerate()
+                .filter(|&(_, &lies)| lies <= self.max_lies)
+                .map(|(i, &lies)| (i, (self.max_lies - lies + 1) as u64))
+        };
+        let total: u64 = weights().map(|(_, weight)| weight).sum();
+        let mut seen = 0;
+        let mut index = 0;
+        for (i, weight) in weights() {
+            index = i;
+            seen += weight;
+            if seen * 2 >= total {
+                break;
+            }
+        }
+        let guess = self.range.start() + index as i32;
+        self.last_guess = Some(guess);
+        self.guesses += 1;
+        guess
+    }
+
+    /// Take the answer to the last guess.
+    ///
+    /// Panics if there was no guess to answer.
+    pub fn answer(&mut self, answer: Answer) -> Result<(), Contradiction> {
+        let guess = self.last_guess.take().expect("answer without a guess");
+        let start = *self.range.start();
+        match answer {
+            Answer::Correct => {
+                self.found = Some(guess);
+                return Ok(());
+            }
+            Answer::Bigger => {
+                self.above = self.above.max(Some(guess));
+                for (i, lies) in self.lies.iter_mut().enumerate() {
+                    if start + i as i32 <= guess {
+                        *lies += 1;
+                    }
+                }
+            }
+            Answer::Smaller => {
+                self.below = Some(self.below.map_or(guess, |below| below.min(guess)));
+                for (i, lies) in self.lies.iter_mut().enumerate() {
+                    if start + i as i32 >= guess {
+                        *lies += 1;
+                    }
+                }
+            }
+        }
+
+        if self.lies.iter().any(|&lies| lies <= self.max_lies) {
+            return Ok(());
+        }
+        Err(match (self.max_lies, self.above, self.below) {
+            (0, Some(above), Some(below)) => Contradiction::Bounds { above, below },
+            (0, None, Some(below)) => Contradiction::BelowRange { below, min: start },
+            (0, Some(above), None) => Contradiction::AboveRange {
+                above,
+                max: *self.range.end(),
+            },
+            (max_lies, _, _) => Contradiction::TooManyLies { max_lies },
+        })
+    }
+
+    /// The secret, once the player said "correct".
+    pub fn found(&self) -> Option<i32> {
+        self.found
+    }
+
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+}
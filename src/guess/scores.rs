@@ -0,0 +1,204 @@
+//! A high-score table for `guess_number`, kept in a plain text file.
+//!
+//! Every line is one won game:
+//!
+//! ```text
+//! alice normal 5 31 12345678901234567890 50,25,37,43,40
+//! ```
+//!
+//! that is the player's name, the difficulty, the number of guesses, the
+//! seconds it took, the seed of the secret number and the guesses
+//! themselves. The seed decides the secret, so [`Score::verify`] can replay
+//! the guesses and tell whether the line was edited by hand. It can only
+//! check that the guesses win in that many tries, not that they were typed
+//! by a person: anyone who runs the generator can still find the secret.
+use super::{Difficulty, Game, Outcome};
+use crate::random::Rng;
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::Path,
+    str::FromStr,
+};
+
+/// One won game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Score {
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub attempts: u32,
+    pub seconds: u64,
+    pub seed: u64,
+    pub guesses: Vec<i32>,
+}
+
+impl Score {
+    /// The score of a won `game` whose secret was drawn from
+    /// `Rng::new(seed)`. Panics if the game was not won.
+    pub fn new(name: &str, difficulty: Difficulty, seed: u64, seconds: u64, game: &Game) -> Self {
+        assert!(game.is_won(), "only won games get a score");
+        Score {
+            name: name.to_string(),
+            difficulty,
+            attempts: game.attempts(),
+            seconds,
+            seed,
+            guesses: game.history().to_vec(),
+        }
+    }
+
+    /// Replay the guesses against the secret from the seed.
+    ///
+    /// ```
+    /// use rust_learning_group_public::guess::{scores::Score, Difficulty, Game};
+    /// use rust_learning_group_public::random::Rng;
+    ///
+    /// let mut game = Game::new(Difficulty::Easy, &mut Rng::new(7));
+    /// let wrong = if game.secret() == 1 { 2 } else { 1 };
+    /// game.guess(wrong);
+    /// game.guess(game.secret());
+    /// let score = Score::new("alice", Difficulty::Easy, 7, 12, &game);
+    /// assert_eq!(score.verify(), Ok(()));
+    ///
+    /// // Dropping the winning guess is caught.
+    /// let mut faked = score.clone();
+    /// faked.attempts = 1;
+    /// faked.guesses.pop();
+    /// assert!(faked.verify().is_err());
+    /// ```
+    pub fn verify(&self) -> Result<(), String> {
+        if self.guesses.len() != self.attempts as usize {
+            return Err(format!(
+                "{} guesses are listed but the score says {}",
+                self.guesses.len(),
+                self.attempts
+            ));
+        }
+
+        let mut game = Game::new(self.difficulty, &mut Rng::new(self.seed));
+        for (i, &guess) in self.guesses.iter().enumerate() {
+            if game.is_finished() {
+                return Err(format!("guess {} comes after the game ended", i + 1));
+            }
+            match game.guess(guess) {
+                Outcome::Win => {}
+                Outcome::Wrong { .. } => {}
+                Outcome::OutOfRange => return Err(format!("guess {} is out of range", i + 1)),
+                Outcome::Lost => return Err(format!("the game was lost at guess {}", i + 1)),
+            }
+        }
+        if game.is_won() {
+            Ok(())
+        } else {
+            Err("the guesses never find the secret".to_string())
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let guesses: Vec<String> = self.guesses.iter().map(i32::to_string).collect();
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.name,
+            self.difficulty,
+            self.attempts,
+            self.seconds,
+            self.seed,
+            guesses.join(",")
+        )
+    }
+}
+
+impl FromStr for Score {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [name, difficulty, attempts, seconds, seed, guesses] = fields[..] else {
+            return Err(format!("expected 6 fields, found {}", fields.len()));
+        };
+        Ok(Score {
+            name: name.to_string(),
+            difficulty: difficulty.parse()?,
+            attempts: number(attempts, "number of guesses")?,
+            seconds: number(seconds, "time")?,
+            seed: number(seed, "seed")?,
+            guesses: guesses
+                .split(',')
+                .map(|guess| guess.parse().map_err(|_| format!("bad guess `{}`", guess)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn number<T: FromStr>(field: &str, what: &str) -> Result<T, String> {
+    field
+        .parse()
+        .map_err(|_| format!("bad {} `{}`", what, field))
+}
+
+/// Whether `name` can be stored: one word, and not starting with `#`, which
+/// would make the line a comment.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('#') && !name.contains(char::is_whitespace)
+}
+
+/// One line of the scores file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// Counted from 1.
+    pub line: usize,
+    pub text: String,
+    /// The score, or why the line could not be read.
+    pub score: Result<Score, String>,
+}
+
+/// Read every score line from `path`. A missing file has no scores.
+///
+/// Lines starting with `#` are comments. A line that cannot be read is
+/// still returned, with the reason, so it can be reported instead of
+/// hiding the rest of the file.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let entries = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| Entry {
+            line: i + 1,
+            text: line.to_string(),
+            score: line.parse(),
+        })
+        .collect();
+    Ok(entries)
+}
+
+/// Add `score` to the end of the file at `path`. A name that
+/// [`is_valid_name`] rejects is an `InvalidInput` error, since the score
+/// could not be read back.
+pub fn append(path: &Path, score: &Score) -> io::Result<()> {
+    if !is_valid_name(&score.name) {
+        let message = format!("`{}` cannot be saved as a name", score.name);
+        return Err(io::Error::new(ErrorKind::InvalidInput, message));
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", score)
+}
+
+/// The best `n` scores of a difficulty: fewest guesses, then fastest.
+pub fn top(scores: &[Score], difficulty: Difficulty, n: usize) -> Vec<&Score> {
+    let mut best: Vec<&Score> = scores
+        .iter()
+        .filter(|score| score.difficulty == difficulty)
+        .collect();
+    best.sort_by_key(|score| (score.attempts, score.seconds));
+    best.truncate(n);
+    best
+}
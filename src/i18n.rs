@@ -0,0 +1,143 @@
+//! Look up messages by key in per-locale catalogs.
+//!
+//! The catalogs live in `locales/<tag>.txt` and are compiled into the
+//! library. The locale is picked from a `--lang <tag>` argument, then from
+//! the `LANG` environment variable, and defaults to English:
+//!
+//! ```text
+//! cargo run --bin cargo_install -- --lang zh
+//! LANG=zh_CN.UTF-8 cargo run --bin cargo_install
+//! ```
+//!
+//! Only prompts and messages meant for humans go through here. What a quiz
+//! is expected to print ("Less", "EXIT", "UNO!", ...) is part of its
+//! specification and stays the same in every language.
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+/// A language we have a catalog for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Zh,
+}
+
+const EN: &str = include_str!("../locales/en.txt");
+const ZH: &str = include_str!("../locales/zh-CN.txt");
+
+impl Locale {
+    /// Parse a tag such as `en`, `zh-CN` or `zh_CN.UTF-8`.
+    ///
+    /// ```
+    /// use rust_learning_group_public::i18n::Locale;
+    ///
+    /// assert_eq!(Locale::parse("zh_CN.UTF-8"), Some(Locale::Zh));
+    /// assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+    /// assert_eq!(Locale::parse("C"), Some(Locale::En));
+    /// assert_eq!(Locale::parse("fr"), None);
+    /// ```
+    pub fn parse(tag: &str) -> Option<Locale> {
+        let language = tag
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "zh" => Some(Locale::Zh),
+            "en" | "c" | "posix" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// Choose a locale from `--lang` or `LANG`, falling back to English.
+    pub fn detect() -> Locale {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let tag = match arg.strip_prefix("--lang=") {
+                Some(tag) => Some(tag.to_string()),
+                None if arg == "--lang" => args.next(),
+                None => None,
+            };
+            if let Some(locale) = tag.as_deref().and_then(Locale::parse) {
+                return locale;
+            }
+        }
+        std::env::var("LANG")
+            .ok()
+            .as_deref()
+            .and_then(Locale::parse)
+            .unwrap_or(Locale::En)
+    }
+
+    /// The locale of this process, detected once on first use.
+    pub fn current() -> Locale {
+        static CURRENT: OnceLock<Locale> = OnceLock::new();
+        *CURRENT.get_or_init(Locale::detect)
+    }
+
+    /// Look up `key` in this locale, then in English.
+    ///
+    /// A missing key is returned as is, so a typo shows up on screen
+    /// instead of as an empty line.
+    pub fn text(self, key: &str) -> &str {
+        self.catalog()
+            .get(key)
+            .or_else(|| Locale::En.catalog().get(key))
+            .map_or(key, String::as_str)
+    }
+
+    fn catalog(self) -> &'static HashMap<&'static str, String> {
+        static EN_CATALOG: OnceLock<HashMap<&str, String>> = OnceLock::new();
+        static ZH_CATALOG: OnceLock<HashMap<&str, String>> = OnceLock::new();
+        match self {
+            Locale::En => EN_CATALOG.get_or_init(|| parse_catalog(EN)),
+            Locale::Zh => ZH_CATALOG.get_or_init(|| parse_catalog(ZH)),
+        }
+    }
+}
+
+/// The message for `key` in the current locale.
+///
+/// ```
+/// use rust_learning_group_public::i18n::{tr, Locale};
+///
+/// if Locale::current() == Locale::En {
+///     assert_eq!(tr("guess_number.title"), "Guess the number!");
+/// }
+/// ```
+pub fn tr(key: &str) -> &str {
+    Locale::current().text(key)
+}
+
+/// Like [`tr`], replacing each `{}` in the message with the next argument.
+///
+/// ```
+/// use rust_learning_group_public::i18n::tr_args;
+///
+/// // Unknown keys come back unchanged, placeholders included.
+/// assert_eq!(tr_args("{} + {}", &[&1, &2]), "1 + 2");
+/// ```
+pub fn tr_args(key: &str, args: &[&dyn Display]) -> String {
+    let mut message = String::new();
+    let mut args = args.iter();
+    let mut parts = tr(key).split("{}").peekable();
+    while let Some(part) = parts.next() {
+        message.push_str(part);
+        if parts.peek().is_some() {
+            match args.next() {
+                Some(arg) => message.push_str(&arg.to_string()),
+                None => message.push_str("{}"),
+            }
+        }
+    }
+    message
+}
+
+fn parse_catalog(source: &'static str) -> HashMap<&'static str, String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim().replace("\\n", "\n")))
+        .collect()
+}
@@ -0,0 +1,499 @@
+//! Reading input without copy-pasting `read_line` into every exercise.
+use crate::i18n::tr;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+/// Why a value could not be read.
+#[derive(Debug)]
+pub enum InputError {
+    Io(std::io::Error),
+    /// The input ended before a value was found.
+    Eof,
+    /// `text` is not a valid value; `reason` is what `parse` said.
+    Parse {
+        text: String,
+        reason: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "cannot read input: {}", e),
+            InputError::Eof => write!(f, "no more input"),
+            InputError::Parse { text, reason } => write!(f, "invalid input `{}`: {}", text, reason),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for InputError {
+    fn from(e: std::io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+/// Read one line and parse it, ignoring leading and trailing whitespace.
+///
+/// This is `buffer.trim().parse::<T>().unwrap()` without the panic.
+///
+/// ```
+/// use rust_learning_group_public::io::{read_value, InputError};
+///
+/// let mut input = "42\nabc\n".as_bytes();
+/// assert_eq!(read_value::<i32>(&mut input).unwrap(), 42);
+/// assert!(matches!(
+///     read_value::<i32>(&mut input),
+///     Err(InputError::Parse { text, .. }) if text == "abc"
+/// ));
+/// assert!(matches!(read_value::<i32>(&mut input), Err(InputError::Eof)));
+/// ```
+pub fn read_value<T>(input: &mut impl BufRead) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut buffer = String::new();
+    if input.read_line(&mut buffer)? == 0 {
+        return Err(InputError::Eof);
+    }
+    parse(buffer.trim())
+}
+
+/// Keep asking until the user types a valid `T`.
+///
+/// This is the `loop { ... break x; }` from `control_flow_3`: print
+/// `prompt` (if not empty), read a line, and on invalid input say so and try
+/// again. With `max_attempts`, the parse error of the last attempt is
+/// returned once that many lines were invalid. End of input and I/O errors
+/// are returned right away.
+///
+/// ```
+/// use rust_learning_group_public::io::{prompt_until_valid, InputError};
+///
+/// let mut input = "one\n2\n".as_bytes();
+/// let mut output = Vec::new();
+/// let x: i32 = prompt_until_valid(&mut input, &mut output, "x? ", None).unwrap();
+/// assert_eq!(x, 2);
+///
+/// let mut input = "one\ntwo\n3\n".as_bytes();
+/// let result = prompt_until_valid::<i32>(&mut input, &mut Vec::new(), "", Some(2));
+/// assert!(matches!(result, Err(InputError::Parse { text, .. }) if text == "two"));
+/// ```
+pub fn prompt_until_valid<T>(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    prompt: &str,
+    max_attempts: Option<usize>,
+) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut attempts = 0;
+    loop {
+        if !prompt.is_empty() {
+            write!(output, "{}", prompt)?;
+            output.flush()?;
+        }
+        match read_value(input) {
+            Ok(value) => break Ok(value),
+            Err(e @ InputError::Parse { .. }) => {
+                attempts += 1;
+                if max_attempts.is_some_and(|max| attempts >= max) {
+                    break Err(e);
+                }
+                writeln!(output, "{}", tr("io.invalid_input"))?;
+            }
+            Err(e) => break Err(e),
+        }
+    }
+}
+
+fn parse<T>(text: &str) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|e: T::Err| InputError::Parse {
+        text: text.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Reads whitespace-separated tokens, like `std::cin >> x` in C++.
+///
+/// Tokens may be spread over any number of lines. One line is buffered at
+/// a time and the buffer is reused, so a million tokens cost a million
+/// parses and nothing more.
+///
+/// ```
+/// use rust_learning_group_public::io::Scanner;
+///
+/// let mut scanner = Scanner::new("42 x\n  3.5\nhello world\n".as_bytes());
+/// let i: i32 = scanner.token().unwrap();
+/// let c: char = scanner.token().unwrap();
+/// let f: f64 = scanner.token().unwrap();
+/// assert_eq!((i, c, f), (42, 'x', 3.5));
+/// assert_eq!(scanner.line().unwrap(), "hello world");
+/// assert_eq!(scanner.token::<i32>(), None);
+/// ```
+pub struct Scanner<R> {
+    reader: R,
+    line: String,
+    pos: usize,
+}
+
+impl<R: BufRead> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Scanner {
+            reader,
+            line: String::new(),
+            pos: 0,
+        }
+    }
+
+    /// Parse the next token as a `T`, or return `None` at the end of input.
+    ///
+    /// Panics if reading fails or the token is not a valid `T`, just like
+    /// `buffer.trim().parse::<T>().unwrap()` would. Use [`Scanner::try_token`]
+    /// to handle bad input instead.
+    pub fn token<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.try_token() {
+            Ok(value) => Some(value),
+            Err(InputError::Eof) => None,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Parse the next token as a `T`.
+    ///
+    /// ```
+    /// use rust_learning_group_public::io::{InputError, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("12 twelve".as_bytes());
+    /// assert_eq!(scanner.try_token::<u8>().unwrap(), 12);
+    /// assert!(matches!(scanner.try_token::<u8>(), Err(InputError::Parse { .. })));
+    /// assert!(matches!(scanner.try_token::<u8>(), Err(InputError::Eof)));
+    /// ```
+    pub fn try_token<T>(&mut self) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse(self.next_token()?.ok_or(InputError::Eof)?)
+    }
+
+    /// The rest of the current line, or the next line if nothing but
+    /// whitespace is left on it. Leading and trailing whitespace is trimmed.
+    /// Returns `None` at the end of input and panics if reading fails.
+    pub fn line(&mut self) -> Option<String> {
+        self.rest_of_line().unwrap()
+    }
+
+    /// Like [`Scanner::line`], but returns errors instead of panicking.
+    pub fn try_line(&mut self) -> Result<String, InputError> {
+        self.rest_of_line()?.ok_or(InputError::Eof)
+    }
+
+    /// The rest of the current line exactly as it was typed, spaces
+    /// included, or the next line if only the line ending is left. The line
+    /// ending itself is dropped. Returns `None` at the end of input and
+    /// panics if reading fails.
+    ///
+    /// ```
+    /// use rust_learning_group_public::io::Scanner;
+    ///
+    /// let mut scanner = Scanner::new("7\n  yes \n".as_bytes());
+    /// assert_eq!(scanner.token::<i32>(), Some(7));
+    /// assert_eq!(scanner.raw_line().unwrap(), "  yes ");
+    /// assert_eq!(scanner.raw_line(), None);
+    /// ```
+    pub fn raw_line(&mut self) -> Option<String> {
+        let rest = self.line[self.pos..].trim_end_matches(['\r', '\n']);
+        if rest.is_empty() && !self.fill().unwrap() {
+            return None;
+        }
+        let line = self.line[self.pos..].trim_end_matches(['\r', '\n']).to_string();
+        self.pos = self.line.len();
+        Some(line)
+    }
+
+    /// Give back the reader, dropping whatever is left of the current line.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn rest_of_line(&mut self) -> std::io::Result<Option<String>> {
+        if self.line[self.pos..].trim().is_empty() && !self.fill()? {
+            return Ok(None);
+        }
+        let line = self.line[self.pos..].trim().to_string();
+        self.pos = self.line.len();
+        Ok(Some(line))
+    }
+
+    fn next_token(&mut self) -> std::io::Result<Option<&str>> {
+        loop {
+            let rest = &self.line[self.pos..];
+            let start = self.pos + (rest.len() - rest.trim_start().len());
+            if start < self.line.len() {
+                let end = self.line[start..]
+                    .find(char::is_whitespace)
+                    .map_or(self.line.len(), |len| start + len);
+                self.pos = end;
+                return Ok(Some(&self.line[start..end]));
+            }
+            if !self.fill()? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Replace the buffer with the next line; `false` at the end of input.
+    fn fill(&mut self) -> std::io::Result<bool> {
+        self.line.clear();
+        self.pos = 0;
+        Ok(self.reader.read_line(&mut self.line)? > 0)
+    }
+}
+
+/// Parse one line into a tuple, like `scanf` in C.
+///
+/// The format is text with a `{}` for each value. A space in the format
+/// matches any amount of whitespace, any other character must appear as
+/// is, and a value ends at whitespace or at the text that follows its
+/// `{}`. Values must be separated by something, so `"{}{}"` is not allowed.
+///
+/// The input is either a [`Scanner`], which supplies the rest of its
+/// current line, or a `&str`. The result is a
+/// `Result<(T1, T2, ...), ScanError>`.
+///
+/// ```
+/// use rust_learning_group_public::{io::Scanner, scan};
+///
+/// let mut scanner = Scanner::new("3 x\n(1, -2)\nfive apples\n".as_bytes());
+/// let (i, c) = scan!(scanner, "{} {}", i32, char).unwrap();
+/// assert_eq!((i, c), (3, 'x'));
+///
+/// let (x, y) = scan!(scanner, "({}, {})", i64, i64).unwrap();
+/// assert_eq!((x, y), (1, -2));
+///
+/// let error = scan!(scanner, "{} {}", u32, String).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "field 1 `five` is not a valid u32: invalid digit found in string"
+/// );
+///
+/// // Types are named the way you wrote them.
+/// use std::net::Ipv4Addr;
+/// let error = scan!("home 1.2.3", "{} {}", String, Ipv4Addr).unwrap_err();
+/// assert!(error.to_string().contains("not a valid Ipv4Addr"));
+///
+/// let (word,) = scan!("hello", "{}", String).unwrap();
+/// assert_eq!(word, "hello");
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $format:expr, $($ty:ty),+ $(,)?) => {
+        match $crate::io::ScanInput::scan_line(&mut $input) {
+            Ok(line) => (|| -> ::std::result::Result<_, $crate::io::ScanError> {
+                let count = [$(stringify!($ty)),+].len();
+                let fields = $crate::io::split_fields(&line, $format, count)?;
+                let mut fields = fields.into_iter().enumerate();
+                Ok(($($crate::io::parse_field::<$ty>(&mut fields, stringify!($ty))?,)+))
+            })(),
+            Err(e) => Err(e),
+        }
+    };
+}
+
+/// Why [`scan!`] could not fill in its tuple.
+#[derive(Debug)]
+pub enum ScanError {
+    Io(std::io::Error),
+    /// There was no line left to read.
+    Eof,
+    /// The text around the values did not match the format.
+    Mismatch {
+        expected: String,
+        found: String,
+    },
+    /// Value number `field` (counting from 1) is not in the line.
+    Missing {
+        field: usize,
+    },
+    /// Value number `field` (counting from 1) could not be parsed.
+    Parse {
+        field: usize,
+        text: String,
+        ty: &'static str,
+        reason: String,
+    },
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScanError::Io(e) => write!(f, "cannot read input: {}", e),
+            ScanError::Eof => write!(f, "no more input"),
+            ScanError::Mismatch { expected, found } => {
+                write!(f, "expected `{}` but found `{}`", expected, found)
+            }
+            ScanError::Missing { field } => write!(f, "field {} is missing", field),
+            ScanError::Parse {
+                field,
+                text,
+                ty,
+                reason,
+            } => write!(
+                f,
+                "field {} `{}` is not a valid {}: {}",
+                field, text, ty, reason
+            ),
+        }
+    }
+}
+
+impl Error for ScanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScanError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Something [`scan!`] can take a line from.
+pub trait ScanInput {
+    fn scan_line(&mut self) -> Result<String, ScanError>;
+}
+
+impl<R: BufRead> ScanInput for Scanner<R> {
+    fn scan_line(&mut self) -> Result<String, ScanError> {
+        self.rest_of_line()
+            .map_err(ScanError::Io)?
+            .ok_or(ScanError::Eof)
+    }
+}
+
+impl ScanInput for &str {
+    fn scan_line(&mut self) -> Result<String, ScanError> {
+        Ok(self.trim().to_string())
+    }
+}
+
+impl<T: ScanInput + ?Sized> ScanInput for &mut T {
+    fn scan_line(&mut self) -> Result<String, ScanError> {
+        (**self).scan_line()
+    }
+}
+
+/// Cut `line` into the texts of the `count` values in `format`.
+///
+/// Used by [`scan!`]; panics if `format` does not have `count` placeholders.
+#[doc(hidden)]
+pub fn split_fields<'a>(
+    line: &'a str,
+    format: &str,
+    count: usize,
+) -> Result<Vec<&'a str>, ScanError> {
+    let literals: Vec<&str> = format.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        count,
+        "`{}` has {} placeholders but {} types were given",
+        format,
+        literals.len() - 1,
+        count
+    );
+    assert!(
+        literals[1..count].iter().all(|literal| !literal.is_empty()),
+        "`{}` has placeholders with nothing between them",
+        format
+    );
+
+    let mut rest = line.trim();
+    let mut fields = Vec::with_capacity(count);
+    for (i, literal) in literals.iter().enumerate() {
+        rest = match_literal(rest, literal)?;
+        if i == count {
+            break;
+        }
+        let stop = literal_start(literals[i + 1]);
+        let len = rest
+            .find(|c: char| c.is_whitespace() || Some(c) == stop)
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(ScanError::Missing { field: i + 1 });
+        }
+        fields.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+    if !rest.is_empty() {
+        return Err(ScanError::Mismatch {
+            expected: "end of line".to_string(),
+            found: rest.to_string(),
+        });
+    }
+    Ok(fields)
+}
+
+/// Parse the next field from [`split_fields`] as a `T`, which [`scan!`]
+/// calls `ty` in errors, spelled as in the format arguments.
+#[doc(hidden)]
+pub fn parse_field<'a, T>(
+    fields: &mut impl Iterator<Item = (usize, &'a str)>,
+    ty: &'static str,
+) -> Result<T, ScanError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (index, text) = fields.next().ok_or(ScanError::Missing { field: 0 })?;
+    text.parse().map_err(|e: T::Err| ScanError::Parse {
+        field: index + 1,
+        text: text.to_string(),
+        ty,
+        reason: e.to_string(),
+    })
+}
+
+/// Skip over `literal` at the start of `text`, where whitespace in the
+/// literal matches any amount of whitespace.
+fn match_literal<'a>(mut text: &'a str, literal: &str) -> Result<&'a str, ScanError> {
+    for c in literal.chars() {
+        if c.is_whitespace() {
+            text = text.trim_start();
+        } else if let Some(rest) = text.strip_prefix(c) {
+            text = rest;
+        } else {
+            return Err(ScanError::Mismatch {
+                expected: literal.trim().to_string(),
+                found: text.chars().take(literal.len().max(1)).collect(),
+            });
+        }
+    }
+    Ok(text)
+}
+
+/// The character a value stops at when `literal` follows it.
+fn literal_start(literal: &str) -> Option<char> {
+    literal.chars().next().filter(|c| !c.is_whitespace())
+}
@@ -0,0 +1,11 @@
+//! Shared helpers for the exercises in `src/bin`.
+//!
+//! Exercises use them as `rust_learning_group_public::<module>`.
+
+pub mod deck;
+pub mod guess;
+pub mod i18n;
+pub mod io;
+pub mod random;
+pub mod testing;
+pub mod uno;
@@ -0,0 +1,221 @@
+//! A small seedable random number generator, so games can be replayed.
+//!
+//! The generator is xoshiro256\*\*, seeded through SplitMix64. The same seed
+//! gives the same numbers on every machine. Programs take the seed from a
+//! `--seed <n>` argument and otherwise pick a fresh one, see [`seed`].
+use std::{
+    fs::File,
+    io::Read,
+    ops::{Bound, RangeBounds},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A xoshiro256\*\* generator.
+///
+/// ```
+/// use rust_learning_group_public::random::Rng;
+///
+/// let mut rng = Rng::new(42);
+/// let secret = rng.range(1..=100);
+/// assert!((1..=100).contains(&secret));
+///
+/// // Same seed, same numbers.
+/// assert_eq!(Rng::new(42).range(1..=100), secret);
+///
+/// let mut cards = [1, 2, 3, 4, 5];
+/// rng.shuffle(&mut cards);
+/// cards.sort();
+/// assert_eq!(cards, [1, 2, 3, 4, 5]);
+///
+/// let picked = rng.sample(&cards, 2);
+/// assert_eq!(picked.len(), 2);
+/// assert_ne!(picked[0], picked[1]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut splitmix = seed;
+        let mut next = || {
+            splitmix = splitmix.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = splitmix;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Rng {
+            state: [next(), next(), next(), next()],
+        }
+    }
+
+    /// A generator seeded with [`seed`]: `--seed` if given, else fresh.
+    pub fn from_args_or_entropy() -> Self {
+        Rng::new(seed())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+        result
+    }
+
+    /// A number in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A uniformly distributed integer in `range`. Panics if it is empty.
+    pub fn range<T: RandomInt>(&mut self, range: impl RangeBounds<T>) -> T {
+        let low = match range.start_bound() {
+            Bound::Included(&x) => x.to_i128(),
+            Bound::Excluded(&x) => x.to_i128() + 1,
+            Bound::Unbounded => T::MIN.to_i128(),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(&x) => x.to_i128(),
+            Bound::Excluded(&x) => x.to_i128() - 1,
+            Bound::Unbounded => T::MAX.to_i128(),
+        };
+        assert!(low <= high, "cannot pick a number from an empty range");
+        let span = (high - low + 1) as u128;
+        let offset = if span > u64::MAX as u128 {
+            self.next_u64()
+        } else {
+            self.below(span as u64)
+        };
+        T::from_i128(low + offset as i128)
+    }
+
+    /// Put `items` in a random order (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// A random element, or `None` if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len() as u64) as usize])
+        }
+    }
+
+    /// `k` elements at distinct positions of `items`, in random order.
+    /// Panics if `k` is larger than `items.len()`.
+    pub fn sample<T: Clone>(&mut self, items: &[T], k: usize) -> Vec<T> {
+        assert!(
+            k <= items.len(),
+            "cannot sample {} of {} items",
+            k,
+            items.len()
+        );
+        let mut indices: Vec<usize> = (0..items.len()).collect();
+        for i in 0..k {
+            let j = i + self.below((items.len() - i) as u64) as usize;
+            indices.swap(i, j);
+        }
+        indices[..k].iter().map(|&i| items[i].clone()).collect()
+    }
+
+    /// A number in `[0, n)` without modulo bias.
+    fn below(&mut self, n: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < limit {
+                return x % n;
+            }
+        }
+    }
+}
+
+/// The integer types [`Rng::range`] can produce.
+pub trait RandomInt: Copy {
+    const MIN: Self;
+    const MAX: Self;
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_random_int {
+    ($($ty:ty),*) => {
+        $(
+            impl RandomInt for $ty {
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_random_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The seed given as `--seed <n>` or `--seed=<n>`, if any. A value that is
+/// not a number is an error rather than ignored, since the run could not be
+/// reproduced.
+pub fn seed_from_args() -> Result<Option<u64>, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--seed=") {
+            Some(value) => value.to_string(),
+            None if arg == "--seed" => args.next().unwrap_or_default(),
+            None => continue,
+        };
+        return match value.parse() {
+            Ok(seed) => Ok(Some(seed)),
+            Err(_) => Err(format!("--seed needs a number, not `{}`", value)),
+        };
+    }
+    Ok(None)
+}
+
+/// A fresh seed from `/dev/urandom`, or from the clock where that is not
+/// available.
+pub fn entropy_seed() -> u64 {
+    let mut bytes = [0; 8];
+    let urandom = File::open("/dev/urandom").and_then(|mut file| file.read_exact(&mut bytes));
+    match urandom {
+        Ok(()) => u64::from_le_bytes(bytes),
+        Err(_) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            now.as_nanos() as u64 ^ (std::process::id() as u64).rotate_left(32)
+        }
+    }
+}
+
+/// The seed a program should use: `--seed` if given, else a fresh one.
+///
+/// Print it when it matters, so the run can be reproduced later. A bad
+/// `--seed` is reported and the program exits with status 2.
+pub fn seed() -> u64 {
+    match seed_from_args() {
+        Ok(seed) => seed.unwrap_or_else(entropy_seed),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Helpers for testing quizzes without spawning a process.
+
+/// Declare a `#[test]` that runs a quiz on `input` and checks its output.
+///
+/// The quiz is any function shaped like the ones in the exercises,
+/// `fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()>`.
+/// If the output differs, the test fails with a line-by-line diff.
+///
+/// ```
+/// use rust_learning_group_public::io_test;
+/// use std::io::{self, BufRead, Write};
+///
+/// fn quiz(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+///     let mut line = String::new();
+///     input.read_line(&mut line)?;
+///     writeln!(output, "{}", line.trim().to_uppercase())
+/// }
+///
+/// io_test!(shout, quiz, input: "hello\n", output: "HELLO\n");
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! io_test {
+    ($name:ident, $quiz:path, input: $input:expr, output: $output:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            let mut input: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref($input);
+            let mut output = ::std::vec::Vec::new();
+            $quiz(&mut input, &mut output).expect("the quiz failed to read or write");
+            $crate::testing::assert_output($output, &output);
+        }
+    };
+}
+
+/// Panic with a readable diff if `actual` is not `expected`.
+///
+/// ```
+/// use rust_learning_group_public::testing::assert_output;
+///
+/// assert_output("UNO!\n4\n", b"UNO!\n4\n");
+/// ```
+///
+/// A mismatch is reported like this:
+///
+/// ```text
+/// output does not match (- expected, + actual):
+///   UNO!
+/// - 4
+/// + 5
+/// ```
+#[track_caller]
+pub fn assert_output(expected: &str, actual: &[u8]) {
+    let actual = String::from_utf8_lossy(actual);
+    if expected != actual {
+        panic!(
+            "output does not match (- expected, + actual):\n{}",
+            diff_lines(expected, &actual)
+        );
+    }
+}
+
+/// A unified-style diff of two texts, based on their longest common
+/// subsequence of lines.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.split_inclusive('\n').collect();
+    let new: Vec<&str> = actual.split_inclusive('\n').collect();
+
+    // common[i][j]: length of the LCS of old[i..] and new[j..].
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            push_diff_line(&mut diff, ' ', old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            push_diff_line(&mut diff, '-', old[i]);
+            i += 1;
+        } else {
+            push_diff_line(&mut diff, '+', new[j]);
+            j += 1;
+        }
+    }
+    diff
+}
+
+fn push_diff_line(diff: &mut String, sign: char, line: &str) {
+    diff.push(sign);
+    diff.push(' ');
+    match line.strip_suffix('\n') {
+        Some(line) => diff.push_str(line),
+        None => diff.push_str(&format!("{}  (no newline at end)", line)),
+    }
+    diff.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_output, diff_lines};
+    use std::io::{self, BufRead, Write};
+
+    fn shout(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        let mut line = String::new();
+        input.read_line(&mut line)?;
+        writeln!(output, "{}", line.trim().to_uppercase())
+    }
+
+    crate::io_test!(io_test_runs_the_quiz, shout, input: "hello\n", output: "HELLO\n");
+
+    #[test]
+    fn diff_of_a_changed_line() {
+        assert_eq!(diff_lines("UNO!\n4\n", "UNO!\n5\n"), "  UNO!\n- 4\n+ 5\n");
+    }
+
+    #[test]
+    fn diff_of_an_extra_line() {
+        assert_eq!(diff_lines("a\nc\n", "a\nb\nc\n"), "  a\n+ b\n  c\n");
+    }
+
+    #[test]
+    fn diff_of_a_missing_final_newline() {
+        assert_eq!(
+            diff_lines("a\nb\n", "a\nb"),
+            "  a\n- b\n+ b  (no newline at end)\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "output does not match (- expected, + actual):\n  UNO!\n- 4\n+ 5\n")]
+    fn mismatch_panics_with_the_diff() {
+        assert_output("UNO!\n4\n", b"UNO!\n5\n");
+    }
+}
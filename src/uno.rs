@@ -0,0 +1,728 @@
+//! A complete game of UNO, the grown-up version of the quiz in
+//! `control_flow_3`.
+//!
+//! The deck has the 108 cards of the real game: in each of the four colors
+//! one 0, two of each number from 1 to 9 and two each of Skip, Reverse and
+//! Draw Two, plus four Wild and four Wild Draw Four cards. Between 2 and 10
+//! players take turns; on your turn you either play a card that matches the
+//! top of the discard pile or draw one card.
+//!
+//! A few rules need a choice, and this engine picks the simplest one:
+//!
+//! - The first card turned up is always a number card. Other cards are put
+//!   back at the bottom of the draw pile.
+//! - After drawing, you may play the card you drew if it matches, or pass.
+//! - Wild Draw Four may only be played if you have no card of the current
+//!   color. There is no challenge.
+//! - Call UNO with [`Game::call_uno`] before playing your second to last
+//!   card. The other players always notice if you forget, and you draw
+//!   [`UNO_PENALTY`] cards.
+//!
+//! Games are dealt from a [`Rng`], so the same seed gives the same game:
+//!
+//! ```
+//! use rust_learning_group_public::random::Rng;
+//! use rust_learning_group_public::uno::{Card, Color, Game, DECK_SIZE};
+//!
+//! let mut game = Game::new(4, &mut Rng::new(7)).unwrap();
+//! while game.winner().is_none() {
+//!     let hand = game.hand(game.current_player());
+//!     match hand.iter().copied().find(|&card| game.can_play(card)) {
+//!         Some(card) => {
+//!             if hand.len() == 2 {
+//!                 game.call_uno().unwrap();
+//!             }
+//!             game.play(card, Some(Color::Red)).unwrap();
+//!         }
+//!         None => match game.draw().unwrap() {
+//!             Some(card) if game.can_play(card) => game.play(card, Some(Color::Blue)).unwrap(),
+//!             _ => game.pass().unwrap(),
+//!         },
+//!     }
+//! }
+//!
+//! let winner = game.winner().unwrap();
+//! assert!(game.hand(winner).is_empty());
+//! // No card is ever lost or made up.
+//! let in_hands: usize = (0..4).map(|player| game.hand(player).len()).sum();
+//! assert_eq!(in_hands + game.draw_pile_len() + game.discard_pile().len(), DECK_SIZE);
+//! ```
+use crate::random::Rng;
+use std::{fmt, str::FromStr};
+
+/// The number of cards in a full deck.
+pub const DECK_SIZE: usize = 108;
+
+/// How many cards everyone gets at the start.
+pub const HAND_SIZE: usize = 7;
+
+/// How many cards you draw for forgetting to call UNO.
+pub const UNO_PENALTY: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+}
+
+impl Color {
+    pub const ALL: [Color; 4] = [Color::Red, Color::Yellow, Color::Green, Color::Blue];
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Color::Red => "red",
+            Color::Yellow => "yellow",
+            Color::Green => "green",
+            Color::Blue => "blue",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "red" | "r" => Ok(Color::Red),
+            "yellow" | "y" => Ok(Color::Yellow),
+            "green" | "g" => Ok(Color::Green),
+            "blue" | "b" => Ok(Color::Blue),
+            other => Err(format!("unknown color `{}`", other)),
+        }
+    }
+}
+
+/// A card, written like `red 7`, `blue skip`, `green reverse`, `yellow +2`,
+/// `wild` or `wild +4`.
+///
+/// ```
+/// use rust_learning_group_public::uno::{Card, Color};
+///
+/// let card: Card = "yellow +2".parse().unwrap();
+/// assert_eq!(card, Card::DrawTwo(Color::Yellow));
+/// assert_eq!(card.color(), Some(Color::Yellow));
+/// assert_eq!(Card::WildDrawFour.to_string(), "wild +4");
+/// assert!("purple 3".parse::<Card>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Card {
+    Number(Color, u8),
+    /// The next player loses their turn.
+    Skip(Color),
+    /// Play goes the other way round.
+    Reverse(Color),
+    /// The next player draws two cards and loses their turn.
+    DrawTwo(Color),
+    /// Matches anything; the player picks the next color.
+    Wild,
+    /// Like `Wild`, and the next player draws four cards and loses their
+    /// turn.
+    WildDrawFour,
+}
+
+impl Card {
+    /// The color printed on the card, `None` for wild cards.
+    pub fn color(&self) -> Option<Color> {
+        match *self {
+            Card::Number(color, _)
+            | Card::Skip(color)
+            | Card::Reverse(color)
+            | Card::DrawTwo(color) => Some(color),
+            Card::Wild | Card::WildDrawFour => None,
+        }
+    }
+
+    pub fn is_wild(&self) -> bool {
+        self.color().is_none()
+    }
+
+    /// Whether two cards show the same number or the same action, whatever
+    /// their colors.
+    fn same_face(&self, other: &Card) -> bool {
+        match (self, other) {
+            (Card::Number(_, a), Card::Number(_, b)) => a == b,
+            (Card::Skip(_), Card::Skip(_))
+            | (Card::Reverse(_), Card::Reverse(_))
+            | (Card::DrawTwo(_), Card::DrawTwo(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Card::Number(color, n) => write!(f, "{} {}", color, n),
+            Card::Skip(color) => write!(f, "{} skip", color),
+            Card::Reverse(color) => write!(f, "{} reverse", color),
+            Card::DrawTwo(color) => write!(f, "{} +2", color),
+            Card::Wild => write!(f, "wild"),
+            Card::WildDrawFour => write!(f, "wild +4"),
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<String> = s.split_whitespace().map(str::to_ascii_lowercase).collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        match words[..] {
+            ["wild"] => Ok(Card::Wild),
+            ["wild", "+4"] => Ok(Card::WildDrawFour),
+            [color, face] => {
+                let color = color.parse()?;
+                match face {
+                    "skip" => Ok(Card::Skip(color)),
+                    "reverse" => Ok(Card::Reverse(color)),
+                    "+2" => Ok(Card::DrawTwo(color)),
+                    _ => match face.parse() {
+                        Ok(n) if n <= 9 => Ok(Card::Number(color, n)),
+                        _ => Err(format!("unknown card `{}`", s.trim())),
+                    },
+                }
+            }
+            _ => Err(format!("unknown card `{}`", s.trim())),
+        }
+    }
+}
+
+/// All 108 cards, in order.
+pub fn full_deck() -> Vec<Card> {
+    let mut deck = Vec::with_capacity(DECK_SIZE);
+    for color in Color::ALL {
+        deck.push(Card::Number(color, 0));
+        for _ in 0..2 {
+            for n in 1..=9 {
+                deck.push(Card::Number(color, n));
+            }
+            deck.push(Card::Skip(color));
+            deck.push(Card::Reverse(color));
+            deck.push(Card::DrawTwo(color));
+        }
+    }
+    for _ in 0..4 {
+        deck.push(Card::Wild);
+        deck.push(Card::WildDrawFour);
+    }
+    deck
+}
+
+/// Which way the turns go.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From player 0 to player 1 and so on.
+    Forward,
+    Backward,
+}
+
+/// Why a move was not allowed. The game is unchanged after an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// UNO needs 2 to 10 players.
+    PlayerCount(usize),
+    /// The deck is too small to deal and turn up a number card.
+    NotEnoughCards,
+    /// Somebody has already won.
+    GameOver,
+    NotInHand(Card),
+    DoesNotMatch {
+        card: Card,
+        top: Card,
+        color: Color,
+    },
+    /// A wild card was played without picking a color.
+    MissingColor,
+    /// Wild Draw Four was played while holding a card of the current color.
+    IllegalWildDrawFour,
+    /// Only one card may be drawn each turn.
+    AlreadyDrew,
+    /// After drawing, only the drawn card may be played.
+    NotTheDrawnCard(Card),
+    /// You can only pass after drawing.
+    MustDrawFirst,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::PlayerCount(n) => write!(f, "UNO needs 2 to 10 players, not {}", n),
+            Error::NotEnoughCards => write!(f, "not enough cards to start a game"),
+            Error::GameOver => write!(f, "the game is over"),
+            Error::NotInHand(card) => write!(f, "you don't have {}", card),
+            Error::DoesNotMatch { card, top, color } => {
+                write!(f, "{} can't go on {} (the color is {})", card, top, color)
+            }
+            Error::MissingColor => write!(f, "pick a color for the wild card"),
+            Error::IllegalWildDrawFour => {
+                write!(
+                    f,
+                    "wild +4 is only allowed without a card of the current color"
+                )
+            }
+            Error::AlreadyDrew => write!(f, "you have already drawn a card"),
+            Error::NotTheDrawnCard(card) => {
+                write!(
+                    f,
+                    "after drawing you can only play the drawn card, not {}",
+                    card
+                )
+            }
+            Error::MustDrawFirst => write!(f, "draw a card before passing"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A game in progress. Players are numbered from 0, and player 0 starts.
+#[derive(Clone, Debug)]
+pub struct Game {
+    hands: Vec<Vec<Card>>,
+    /// The top card is the last one.
+    draw_pile: Vec<Card>,
+    /// The top card is the last one.
+    discard_pile: Vec<Card>,
+    /// The color to match, which a wild card may have changed.
+    color: Color,
+    current: usize,
+    direction: Direction,
+    called_uno: bool,
+    /// The card drawn this turn, `Some(None)` if the piles were empty.
+    drawn: Option<Option<Card>>,
+    winner: Option<usize>,
+    /// Reshuffles the discard pile when the draw pile runs out.
+    rng: Rng,
+}
+
+impl Game {
+    /// Shuffle a full deck and deal it to `players` players.
+    pub fn new(players: usize, rng: &mut Rng) -> Result<Game, Error> {
+        let mut deck = full_deck();
+        rng.shuffle(&mut deck);
+        Game::with_deck(players, deck, rng)
+    }
+
+    /// Deal `deck` as it is, without shuffling: the first cards go to the
+    /// players in turn, then the first number card left is turned up. Use
+    /// it to set up a game in a test. `rng` is only used when the draw pile
+    /// runs out.
+    ///
+    /// ```
+    /// use rust_learning_group_public::random::Rng;
+    /// use rust_learning_group_public::uno::{Card::*, Color::*, Game};
+    ///
+    /// let mut deck = vec![Number(Red, 1), Number(Blue, 2)];
+    /// deck.extend([Skip(Red), Number(Blue, 5)].repeat(6));
+    /// deck.extend([Wild, Number(Red, 8), Number(Green, 9)]);
+    /// let mut game = Game::with_deck(2, deck, &mut Rng::new(0)).unwrap();
+    ///
+    /// // Wild went to the bottom, red 8 is turned up.
+    /// assert_eq!(game.top_card(), Number(Red, 8));
+    /// game.play(Number(Red, 1), None).unwrap();
+    /// assert_eq!(game.current_player(), 1);
+    /// assert!(game.play(Number(Red, 1), None).is_err());
+    /// ```
+    pub fn with_deck(players: usize, deck: Vec<Card>, rng: &mut Rng) -> Result<Game, Error> {
+        if !(2..=10).contains(&players) {
+            return Err(Error::PlayerCount(players));
+        }
+        let mut draw_pile = deck;
+        draw_pile.reverse();
+        if draw_pile.len() <= players * HAND_SIZE {
+            return Err(Error::NotEnoughCards);
+        }
+
+        let mut hands = vec![Vec::with_capacity(HAND_SIZE); players];
+        for _ in 0..HAND_SIZE {
+            for hand in &mut hands {
+                hand.extend(draw_pile.pop());
+            }
+        }
+
+        let Some(index) = draw_pile
+            .iter()
+            .rposition(|card| matches!(card, Card::Number(..)))
+        else {
+            return Err(Error::NotEnoughCards);
+        };
+        // Cards turned up before the first number card go to the bottom.
+        let skipped = draw_pile.split_off(index + 1);
+        let first = draw_pile.pop().expect("the number card is on top now");
+        draw_pile.splice(0..0, skipped);
+
+        Ok(Game {
+            hands,
+            draw_pile,
+            discard_pile: vec![first],
+            color: first.color().expect("number cards have a color"),
+            current: 0,
+            direction: Direction::Forward,
+            called_uno: false,
+            drawn: None,
+            winner: None,
+            rng: Rng::new(rng.next_u64()),
+        })
+    }
+
+    pub fn players(&self) -> usize {
+        self.hands.len()
+    }
+
+    /// Whose turn it is.
+    pub fn current_player(&self) -> usize {
+        self.current
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn hand(&self, player: usize) -> &[Card] {
+        &self.hands[player]
+    }
+
+    pub fn top_card(&self) -> Card {
+        *self
+            .discard_pile
+            .last()
+            .expect("the discard pile is never empty")
+    }
+
+    /// The color the next card must match.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn discard_pile(&self) -> &[Card] {
+        &self.discard_pile
+    }
+
+    pub fn draw_pile_len(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    /// The player who got rid of all their cards.
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    /// Whether the current player may play `card` now, if they hold it.
+    pub fn can_play(&self, card: Card) -> bool {
+        self.check(card).is_ok()
+    }
+
+    /// Say "UNO!" before playing your second to last card.
+    pub fn call_uno(&mut self) -> Result<(), Error> {
+        self.check_running()?;
+        self.called_uno = true;
+        Ok(())
+    }
+
+    /// Play `card` from the current player's hand. `color` is the color
+    /// picked for a wild card and is ignored for other cards.
+    pub fn play(&mut self, card: Card, color: Option<Color>) -> Result<(), Error> {
+        self.check(card)?;
+        let color = match card.color() {
+            Some(color) => color,
+            None => color.ok_or(Error::MissingColor)?,
+        };
+
+        let player = self.current;
+        let hand = &mut self.hands[player];
+        let index = hand.iter().position(|&c| c == card).expect("checked above");
+        hand.remove(index);
+        self.discard_pile.push(card);
+        self.color = color;
+
+        match self.hands[player].len() {
+            0 => {
+                self.winner = Some(player);
+                return Ok(());
+            }
+            1 if !self.called_uno => self.draw_cards(player, UNO_PENALTY),
+            _ => {}
+        }
+
+        match card {
+            Card::Number(..) | Card::Wild => self.end_turn(1),
+            Card::Skip(_) => self.end_turn(2),
+            Card::Reverse(_) => {
+                self.direction = match self.direction {
+                    Direction::Forward => Direction::Backward,
+                    Direction::Backward => Direction::Forward,
+                };
+                // With two players Reverse works like Skip.
+                self.end_turn(if self.players() == 2 { 2 } else { 1 });
+            }
+            Card::DrawTwo(_) => {
+                self.draw_cards(self.next_player(1), 2);
+                self.end_turn(2);
+            }
+            Card::WildDrawFour => {
+                self.draw_cards(self.next_player(1), 4);
+                self.end_turn(2);
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw one card instead of playing. Afterwards play the drawn card if
+    /// it matches, or [`pass`](Game::pass). `None` means both piles are
+    /// empty.
+    pub fn draw(&mut self) -> Result<Option<Card>, Error> {
+        self.check_running()?;
+        if self.drawn.is_some() {
+            return Err(Error::AlreadyDrew);
+        }
+        let card = self.draw_card();
+        self.hands[self.current].extend(card);
+        self.drawn = Some(card);
+        Ok(card)
+    }
+
+    /// End the turn after drawing.
+    pub fn pass(&mut self) -> Result<(), Error> {
+        self.check_running()?;
+        if self.drawn.is_none() {
+            return Err(Error::MustDrawFirst);
+        }
+        self.end_turn(1);
+        Ok(())
+    }
+
+    fn check_running(&self) -> Result<(), Error> {
+        match self.winner {
+            Some(_) => Err(Error::GameOver),
+            None => Ok(()),
+        }
+    }
+
+    /// Everything that would make playing `card` now illegal.
+    fn check(&self, card: Card) -> Result<(), Error> {
+        self.check_running()?;
+        let hand = &self.hands[self.current];
+        if !hand.contains(&card) {
+            return Err(Error::NotInHand(card));
+        }
+        match self.drawn {
+            Some(Some(drawn)) if card == drawn => {}
+            // Nothing was drawn because both piles were empty: pass.
+            Some(_) => return Err(Error::NotTheDrawnCard(card)),
+            None => {}
+        }
+
+        let top = self.top_card();
+        match card {
+            Card::Wild => Ok(()),
+            Card::WildDrawFour => {
+                if hand.iter().any(|c| c.color() == Some(self.color)) {
+                    Err(Error::IllegalWildDrawFour)
+                } else {
+                    Ok(())
+                }
+            }
+            _ if card.color() == Some(self.color) || card.same_face(&top) => Ok(()),
+            _ => Err(Error::DoesNotMatch {
+                card,
+                top,
+                color: self.color,
+            }),
+        }
+    }
+
+    /// The player `steps` seats away in the current direction.
+    fn next_player(&self, steps: usize) -> usize {
+        let players = self.players();
+        match self.direction {
+            Direction::Forward => (self.current + steps) % players,
+            Direction::Backward => (self.current + players - steps % players) % players,
+        }
+    }
+
+    /// Move `steps` seats on, so 2 skips a player.
+    fn end_turn(&mut self, steps: usize) {
+        self.current = self.next_player(steps);
+        self.called_uno = false;
+        self.drawn = None;
+    }
+
+    fn draw_cards(&mut self, player: usize, n: usize) {
+        for _ in 0..n {
+            let card = self.draw_card();
+            self.hands[player].extend(card);
+        }
+    }
+
+    /// Take the top card of the draw pile, first shuffling the discard
+    /// pile, except its top card, into a new draw pile if it is empty.
+    fn draw_card(&mut self) -> Option<Card> {
+        if self.draw_pile.is_empty() {
+            let top = self
+                .discard_pile
+                .pop()
+                .expect("the discard pile is never empty");
+            self.draw_pile = std::mem::replace(&mut self.discard_pile, vec![top]);
+            self.rng.shuffle(&mut self.draw_pile);
+        }
+        self.draw_pile.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Card::*, Color::*, *};
+
+    /// A deck that deals `hands` to the players and turns up `rest[0]`,
+    /// which must be a number card. The rest is drawn in order.
+    fn deck(hands: &[[Card; HAND_SIZE]], rest: &[Card]) -> Vec<Card> {
+        let mut deck = Vec::new();
+        for i in 0..HAND_SIZE {
+            deck.extend(hands.iter().map(|hand| hand[i]));
+        }
+        deck.extend(rest);
+        deck
+    }
+
+    fn game(hands: &[[Card; HAND_SIZE]], rest: &[Card]) -> Game {
+        Game::with_deck(hands.len(), deck(hands, rest), &mut Rng::new(0)).unwrap()
+    }
+
+    const GREENS: [Card; HAND_SIZE] = [Number(Green, 9); HAND_SIZE];
+
+    #[test]
+    fn skip_skips_the_next_player() {
+        let mut hand = GREENS;
+        hand[0] = Skip(Red);
+        let mut game = game(&[hand, GREENS, GREENS], &[Number(Red, 5)]);
+        game.play(Skip(Red), None).unwrap();
+        assert_eq!(game.current_player(), 2);
+    }
+
+    #[test]
+    fn reverse_turns_the_other_way() {
+        let mut hand = GREENS;
+        hand[0] = Reverse(Red);
+        let mut game = game(&[hand, GREENS, GREENS], &[Number(Red, 5)]);
+        game.play(Reverse(Red), None).unwrap();
+        assert_eq!(game.direction(), Direction::Backward);
+        assert_eq!(game.current_player(), 2);
+    }
+
+    #[test]
+    fn reverse_with_two_players_skips() {
+        let mut hand = GREENS;
+        hand[0] = Reverse(Red);
+        let mut game = game(&[hand, GREENS], &[Number(Red, 5)]);
+        game.play(Reverse(Red), None).unwrap();
+        assert_eq!(game.current_player(), 0);
+    }
+
+    #[test]
+    fn draw_two_makes_the_next_player_draw_and_skips_them() {
+        let mut hand = GREENS;
+        hand[0] = DrawTwo(Red);
+        let rest = [Number(Red, 5), Number(Blue, 1), Number(Blue, 2)];
+        let mut game = game(&[hand, GREENS, GREENS], &rest);
+        game.play(DrawTwo(Red), None).unwrap();
+        assert_eq!(game.hand(1).len(), HAND_SIZE + 2);
+        assert!(game.hand(1).contains(&Number(Blue, 2)));
+        assert_eq!(game.current_player(), 2);
+    }
+
+    #[test]
+    fn wild_draw_four_needs_no_card_of_the_color() {
+        let mut legal = GREENS;
+        legal[0] = WildDrawFour;
+        let mut illegal = legal;
+        illegal[1] = Number(Red, 1);
+        let rest = [Number(Red, 5)];
+
+        let mut game1 = game(&[illegal, GREENS, GREENS], &rest);
+        assert_eq!(
+            game1.play(WildDrawFour, Some(Blue)),
+            Err(Error::IllegalWildDrawFour)
+        );
+        assert!(!game1.can_play(WildDrawFour));
+
+        let rest = [Number(Red, 5), Number(Blue, 1), Number(Blue, 2)];
+        let rest = [&rest[..], &[Number(Blue, 3), Number(Blue, 4)]].concat();
+        let mut game2 = game(&[legal, GREENS, GREENS], &rest);
+        assert_eq!(game2.play(WildDrawFour, None), Err(Error::MissingColor));
+        game2.play(WildDrawFour, Some(Blue)).unwrap();
+        assert_eq!(game2.color(), Blue);
+        assert_eq!(game2.hand(1).len(), HAND_SIZE + 4);
+        assert_eq!(game2.current_player(), 2);
+    }
+
+    /// Player 0 plays red cards down to two while player 1 draws and
+    /// passes, then plays the second to last card.
+    fn play_down_to_one_card(call_uno: bool) -> Game {
+        let reds = [1, 2, 3, 4, 5, 6, 7].map(|n| Number(Red, n));
+        let mut rest = vec![Number(Red, 0)];
+        rest.extend([Number(Yellow, 8); 10]);
+        let mut game = game(&[reds, GREENS], &rest);
+        for card in &reds[..HAND_SIZE - 2] {
+            game.play(*card, None).unwrap();
+            assert_eq!(game.draw().unwrap(), Some(Number(Yellow, 8)));
+            game.pass().unwrap();
+        }
+        assert_eq!(game.hand(0).len(), 2);
+        if call_uno {
+            game.call_uno().unwrap();
+        }
+        game.play(reds[HAND_SIZE - 2], None).unwrap();
+        game
+    }
+
+    #[test]
+    fn calling_uno_avoids_the_penalty() {
+        assert_eq!(play_down_to_one_card(true).hand(0), [Number(Red, 7)]);
+    }
+
+    #[test]
+    fn forgetting_uno_costs_cards() {
+        let game = play_down_to_one_card(false);
+        assert_eq!(game.hand(0).len(), 1 + UNO_PENALTY);
+    }
+
+    #[test]
+    fn the_discard_pile_is_reshuffled_when_the_draw_pile_runs_out() {
+        let mut reds = GREENS;
+        reds[0] = Number(Red, 1);
+        reds[1] = Number(Red, 2);
+        let mut game = game(&[reds, GREENS], &[Number(Red, 0), Number(Blue, 3)]);
+
+        game.play(Number(Red, 1), None).unwrap();
+        assert_eq!(game.draw().unwrap(), Some(Number(Blue, 3)));
+        game.pass().unwrap();
+        assert_eq!(game.draw_pile_len(), 0);
+
+        game.play(Number(Red, 2), None).unwrap();
+        let drawn = game.draw().unwrap().unwrap();
+        assert!([Number(Red, 0), Number(Red, 1)].contains(&drawn));
+        assert_eq!(game.discard_pile(), [Number(Red, 2)]);
+        assert_eq!(game.draw_pile_len(), 1);
+    }
+
+    #[test]
+    fn with_both_piles_empty_drawing_only_lets_you_pass() {
+        let mut reds = GREENS;
+        reds[0] = Number(Red, 1);
+        let mut game = game(&[reds, GREENS], &[Number(Red, 0)]);
+
+        assert_eq!(game.draw(), Ok(None));
+        assert_eq!(
+            game.play(Number(Red, 1), None),
+            Err(Error::NotTheDrawnCard(Number(Red, 1)))
+        );
+        game.pass().unwrap();
+        assert_eq!(game.current_player(), 1);
+    }
+}